@@ -9,6 +9,16 @@ pub enum Error {
     InvalidCharacterCount { len: usize, min: usize, max: usize },
     #[error("Expected {min}-{max} bytes, found {len}")]
     InvalidLength { len: usize, min: usize, max: usize },
+    #[error("Expected {min}-{max} source objects, found {len}")]
+    InvalidSourceCount { len: usize, min: usize, max: usize },
+    #[error("A parallel composite upload must have at least one part")]
+    InvalidPartCount,
+    #[error("Expected a part of {expected} bytes, found {found}")]
+    PartSizeMismatch { expected: u64, found: u64 },
+    #[error("A sliced download must have at least one slice")]
+    InvalidSliceCount,
+    #[error("Received {found} bytes for a slice of {expected} bytes")]
+    SliceLengthMismatch { expected: u64, found: u64 },
     #[error("Character '{1}' @ {0} is not allowed")]
     InvalidCharacter(usize, char),
     #[error("Prefix {0} is not allowed")]
@@ -17,6 +27,12 @@ pub enum Error {
     InvalidSequence(&'static str),
     #[error("Failed to parse URI")]
     InvalidUri(UriError),
+    #[error("Byte range is invalid")]
+    InvalidRange,
+    #[error("A chunk of {len} bytes is not a multiple of 256KiB")]
+    UnalignedChunk { len: u64 },
+    #[error("Only the final chunk of an upload can be empty")]
+    EmptyChunk,
     #[error("Header value is invalid")]
     InvalidHeaderValue,
     #[error("HTTP error")]
@@ -45,6 +61,26 @@ pub enum Error {
     Io(#[source] IoError),
     #[error("Unable to decode base64")]
     Base64Decode(#[source] data_encoding::DecodeError),
+    #[error("{algorithm} hash mismatch: expected {expected}, calculated {calculated}")]
+    HashMismatch {
+        algorithm: &'static str,
+        expected: String,
+        calculated: String,
+    },
+    #[error("The resumable upload session has expired or doesn't exist")]
+    ExpiredSession,
+    #[error("Expected object generation {expected}, found {found}")]
+    GenerationMismatch { expected: i64, found: i64 },
+    #[error("Metadata is missing the '{0}' field")]
+    MissingMetadata(&'static str),
+    #[error("No hash was available to verify the content against")]
+    MissingHash,
+    #[error("An HMAC key can only be updated to be active or inactive")]
+    InvalidHmacKeyState,
+    #[error("At least one permission must be tested")]
+    MissingPermissions,
+    #[error("Objects can only be granted the READER or OWNER role")]
+    InvalidObjectRole,
     #[error("Unable to encode url")]
     UrlEncode(#[source] serde_urlencoded::ser::Error),
 }
@@ -0,0 +1,463 @@
+//! Helper facilities for calculating and verifying the
+//! [hashes](https://cloud.google.com/storage/docs/hashes-etags) GCS stores
+//! for every object
+
+use crate::{
+    error::Error,
+    signing::{DigestAlgorithm, DigestCalulator},
+};
+
+/// The reversed Castagnoli polynomial
+const POLY: u32 = 0x82f6_3b78;
+
+/// Generates the 8 lookup tables used to calculate the CRC32C checksum 8
+/// bytes at a time
+const fn crc32c_tables() -> [[u32; 256]; 8] {
+    let mut tables = [[0u32; 256]; 8];
+
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLY
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        tables[0][i] = crc;
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < 256 {
+        let mut t = 1;
+        while t < 8 {
+            let prev = tables[t - 1][i];
+            tables[t][i] = (prev >> 8) ^ tables[0][(prev & 0xff) as usize];
+            t += 1;
+        }
+        i += 1;
+    }
+
+    tables
+}
+
+static CRC32C_TABLES: [[u32; 256]; 8] = crc32c_tables();
+
+/// Incrementally calculates the [CRC32C](https://en.wikipedia.org/wiki/Cyclic_redundancy_check)
+/// (Castagnoli) checksum of a stream of bytes, which GCS computes for every
+/// object, including composite ones.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Crc32c {
+    state: u32,
+}
+
+impl Crc32c {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calculates the checksum of a single block of data
+    pub fn checksum(data: &[u8]) -> u32 {
+        let mut crc = Self::new();
+        crc.update(data);
+        crc.finalize()
+    }
+
+    /// Adds more data to the checksum
+    pub fn update(&mut self, data: &[u8]) {
+        let t = &CRC32C_TABLES;
+        let mut crc = !self.state;
+
+        let mut chunks = data.chunks_exact(8);
+        for chunk in &mut chunks {
+            let lo = crc ^ u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            crc = t[7][(lo & 0xff) as usize]
+                ^ t[6][((lo >> 8) & 0xff) as usize]
+                ^ t[5][((lo >> 16) & 0xff) as usize]
+                ^ t[4][(lo >> 24) as usize]
+                ^ t[3][chunk[4] as usize]
+                ^ t[2][chunk[5] as usize]
+                ^ t[1][chunk[6] as usize]
+                ^ t[0][chunk[7] as usize];
+        }
+
+        for &byte in chunks.remainder() {
+            crc = (crc >> 8) ^ t[0][((crc ^ u32::from(byte)) & 0xff) as usize];
+        }
+
+        self.state = !crc;
+    }
+
+    /// The checksum of all the data seen so far
+    pub fn finalize(&self) -> u32 {
+        self.state
+    }
+
+    /// Calculates the checksum of the concatenation of two blocks of data from
+    /// their individual checksums, and the length of the second block, the
+    /// same way GCS does when composing objects
+    pub fn combine(crc_a: u32, crc_b: u32, len_b: u64) -> u32 {
+        fn times(matrix: &[u32; 32], mut vec: u32) -> u32 {
+            let mut sum = 0;
+            let mut i = 0;
+            while vec != 0 {
+                if vec & 1 == 1 {
+                    sum ^= matrix[i];
+                }
+                vec >>= 1;
+                i += 1;
+            }
+            sum
+        }
+
+        fn square(matrix: &[u32; 32]) -> [u32; 32] {
+            let mut squared = [0u32; 32];
+            for (sq, row) in squared.iter_mut().zip(matrix.iter()) {
+                *sq = times(matrix, *row);
+            }
+            squared
+        }
+
+        if len_b == 0 {
+            return crc_a;
+        }
+
+        // The operator for a single zero bit
+        let mut odd = [0u32; 32];
+        odd[0] = POLY;
+        for (n, row) in odd.iter_mut().enumerate().skip(1) {
+            *row = 1 << (n - 1);
+        }
+
+        // The operators for 2 and then 4 zero bits, so that the first
+        // square in the loop is the operator for a single zero byte
+        let mut even = square(&odd);
+        odd = square(&even);
+
+        // Apply len_b zero bytes to crc_a
+        let mut crc = crc_a;
+        let mut len = len_b;
+        loop {
+            even = square(&odd);
+            if len & 1 == 1 {
+                crc = times(&even, crc);
+            }
+            len >>= 1;
+            if len == 0 {
+                break;
+            }
+
+            odd = square(&even);
+            if len & 1 == 1 {
+                crc = times(&odd, crc);
+            }
+            len >>= 1;
+            if len == 0 {
+                break;
+            }
+        }
+
+        crc ^ crc_b
+    }
+}
+
+/// The hashes of an object's content, encoded using base64 the same way as
+/// GCS stores them in the object's metadata and the `x-goog-hash` header
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObjectHashes {
+    /// `CRC32c` checksum, encoded using base64 in big-endian byte order.
+    pub crc32c: Option<String>,
+    /// MD5 hash, encoded using base64.
+    pub md5_hash: Option<String>,
+}
+
+impl ObjectHashes {
+    /// Calculates the hashes of the specified data, the MD5 hash is only
+    /// calculated if a [`DigestCalulator`] is provided
+    pub fn calculate(data: &[u8], digest: Option<&dyn DigestCalulator>) -> Self {
+        Self {
+            crc32c: Some(encode_crc32c(Crc32c::checksum(data))),
+            md5_hash: digest.map(|digest| data_encoding::BASE64.encode(&md5(digest, data))),
+        }
+    }
+
+    /// Verifies the specified data matches these hashes, the MD5 hash is only
+    /// verified if a [`DigestCalulator`] is provided
+    pub fn verify(&self, data: &[u8], digest: Option<&dyn DigestCalulator>) -> Result<(), Error> {
+        self.check(
+            Crc32c::checksum(data),
+            digest.map(|digest| md5(digest, data)),
+        )
+    }
+
+    /// Checks calculated hashes against these hashes. Fails if a hash doesn't
+    /// match, or if none of these hashes could be checked.
+    fn check(&self, crc32c: u32, md5: Option<[u8; 16]>) -> Result<(), Error> {
+        let mut verified = false;
+
+        if let Some(expected) = &self.crc32c {
+            let calculated = encode_crc32c(crc32c);
+            if *expected != calculated {
+                return Err(Error::HashMismatch {
+                    algorithm: "crc32c",
+                    expected: expected.clone(),
+                    calculated,
+                });
+            }
+            verified = true;
+        }
+
+        if let (Some(expected), Some(md5)) = (&self.md5_hash, md5) {
+            let calculated = data_encoding::BASE64.encode(&md5);
+            if *expected != calculated {
+                return Err(Error::HashMismatch {
+                    algorithm: "md5",
+                    expected: expected.clone(),
+                    calculated,
+                });
+            }
+            verified = true;
+        }
+
+        if verified {
+            Ok(())
+        } else {
+            Err(Error::MissingHash)
+        }
+    }
+
+    /// The value of the `x-goog-hash` header used to have GCS verify the
+    /// content of an upload, `None` if there are no hashes
+    pub fn to_header_value(&self) -> Result<Option<http::HeaderValue>, Error> {
+        let hashes: Vec<_> = self
+            .crc32c
+            .iter()
+            .map(|crc| format!("crc32c={crc}"))
+            .chain(self.md5_hash.iter().map(|md5| format!("md5={md5}")))
+            .collect();
+
+        if hashes.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(http::HeaderValue::try_from(hashes.join(","))?))
+    }
+}
+
+fn md5(digest: &dyn DigestCalulator, data: &[u8]) -> [u8; 16] {
+    let mut md5 = [0u8; 16];
+    digest.digest(DigestAlgorithm::Md5, data, &mut md5);
+    md5
+}
+
+pub(crate) fn encode_crc32c(crc: u32) -> String {
+    data_encoding::BASE64.encode(&crc.to_be_bytes())
+}
+
+pub(crate) fn decode_crc32c(crc: &str) -> Option<u32> {
+    let bytes = data_encoding::BASE64.decode(crc.as_bytes()).ok()?;
+    Some(u32::from_be_bytes(bytes.try_into().ok()?))
+}
+
+/// Incrementally verifies that a stream of bytes matches the `CRC32c`
+/// checksum of the expected [`ObjectHashes`]. The MD5 hash can't be
+/// calculated incrementally with a [`DigestCalulator`], so is only checked by
+/// [`ObjectHashes::verify`].
+pub struct HashVerifier {
+    expected: ObjectHashes,
+    crc32c: Crc32c,
+}
+
+impl HashVerifier {
+    pub fn new(expected: ObjectHashes) -> Self {
+        Self {
+            expected,
+            crc32c: Crc32c::new(),
+        }
+    }
+
+    /// Adds more data to the checksum being calculated
+    pub fn update(&mut self, data: &[u8]) {
+        self.crc32c.update(data);
+    }
+
+    /// Checks the hashes of all of the data seen so far against the expected
+    /// hashes. Fails if a hash doesn't match, or if none of the expected
+    /// hashes could be checked.
+    pub fn verify(self) -> Result<(), Error> {
+        self.expected.check(self.crc32c.finalize(), None)
+    }
+}
+
+/// Wraps the body of a download response, verifying its hashes as the data
+/// is read, without needing to hold the entire object in memory.
+///
+/// Once the inner reader reaches EOF, the hashes of all the data read are
+/// checked, and if they don't match an [`std::io::ErrorKind::InvalidData`]
+/// error is returned that wraps the [`Error`] describing the mismatch.
+///
+/// Note the hashes GCS sends are for the stored object, so this can't be used
+/// with ranged downloads, or downloads that were decompressively transcoded.
+pub struct VerifyingReader<R> {
+    inner: R,
+    verifier: Option<HashVerifier>,
+}
+
+impl<R> VerifyingReader<R> {
+    pub fn new(inner: R, verifier: HashVerifier) -> Self {
+        Self {
+            inner,
+            verifier: Some(verifier),
+        }
+    }
+
+    /// Returns the wrapped reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn update(&mut self, data: &[u8]) -> std::io::Result<()> {
+        if data.is_empty() {
+            if let Some(verifier) = self.verifier.take() {
+                verifier
+                    .verify()
+                    .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+            }
+        } else if let Some(verifier) = &mut self.verifier {
+            verifier.update(data);
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: std::io::Read> std::io::Read for VerifyingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+
+        // A read into an empty buffer doesn't mean we've reached EOF
+        if !buf.is_empty() {
+            self.update(&buf[..read])?;
+        }
+
+        Ok(read)
+    }
+}
+
+#[cfg(feature = "async-download")]
+impl<R: futures_util::io::AsyncRead + Unpin> futures_util::io::AsyncRead for VerifyingReader<R> {
+    fn poll_read(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        use std::task::Poll;
+
+        let read = match std::pin::Pin::new(&mut self.inner).poll_read(cx, buf) {
+            Poll::Ready(Ok(read)) => read,
+            other => return other,
+        };
+
+        if !buf.is_empty() {
+            self.update(&buf[..read])?;
+        }
+
+        Poll::Ready(Ok(read))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn calculates_crc32c() {
+        assert_eq!(Crc32c::checksum(b""), 0);
+        assert_eq!(Crc32c::checksum(b"123456789"), 0xe306_9283);
+        assert_eq!(Crc32c::checksum(&[0u8; 32]), 0x8a91_36aa);
+
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+        let mut crc = Crc32c::new();
+        for chunk in data.chunks(13) {
+            crc.update(chunk);
+        }
+        assert_eq!(crc.finalize(), Crc32c::checksum(&data));
+
+        let (a, b) = data.split_at(333);
+        assert_eq!(
+            Crc32c::combine(Crc32c::checksum(a), Crc32c::checksum(b), b.len() as u64),
+            Crc32c::checksum(&data)
+        );
+        assert_eq!(
+            Crc32c::combine(Crc32c::checksum(a), 0, 0),
+            Crc32c::checksum(a)
+        );
+        assert_eq!(
+            decode_crc32c(&encode_crc32c(0xe306_9283)),
+            Some(0xe306_9283)
+        );
+    }
+
+    #[test]
+    fn verifies_hashes() {
+        let hashes = ObjectHashes::calculate(b"123456789", None);
+        assert_eq!(hashes.crc32c.as_deref(), Some("4waSgw=="));
+        assert!(hashes.verify(b"123456789", None).is_ok());
+        assert_eq!(
+            hashes.verify(b"12345678", None).unwrap_err(),
+            Error::HashMismatch {
+                algorithm: "crc32c",
+                expected: "4waSgw==".to_owned(),
+                calculated: encode_crc32c(Crc32c::checksum(b"12345678")),
+            }
+        );
+
+        let md5_only = ObjectHashes {
+            crc32c: None,
+            md5_hash: Some("JfnnlDI7RTiF9RgfG2JNCw==".to_owned()),
+        };
+        assert_eq!(md5_only.verify(b"123456789", None), Err(Error::MissingHash));
+    }
+
+    /// Not a real MD5, just enough to check the digest is used consistently
+    struct XorDigest;
+
+    impl DigestCalulator for XorDigest {
+        fn digest(&self, algorithm: DigestAlgorithm, data: &[u8], output_digest: &mut [u8]) {
+            assert_eq!(algorithm, DigestAlgorithm::Md5);
+            output_digest.fill(0);
+            for byte in data {
+                output_digest[usize::from(*byte) % 16] ^= byte;
+            }
+        }
+    }
+
+    #[test]
+    fn verifies_md5() {
+        let hashes = ObjectHashes::calculate(b"123456789", Some(&XorDigest));
+        assert!(hashes.md5_hash.is_some());
+        assert!(hashes.verify(b"123456789", Some(&XorDigest)).is_ok());
+
+        let md5_only = ObjectHashes {
+            crc32c: None,
+            ..hashes
+        };
+        assert!(md5_only.verify(b"123456789", Some(&XorDigest)).is_ok());
+        assert!(matches!(
+            md5_only.verify(b"12345678", Some(&XorDigest)),
+            Err(Error::HashMismatch {
+                algorithm: "md5",
+                ..
+            })
+        ));
+
+        // The streaming verifier can only check the CRC32c checksum
+        let mut verifier = HashVerifier::new(md5_only);
+        verifier.update(b"123456789");
+        assert_eq!(verifier.verify(), Err(Error::MissingHash));
+    }
+}
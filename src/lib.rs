@@ -7,6 +7,7 @@ mod v1;
 pub use crate::v1::*;
 
 pub mod error;
+pub mod hashing;
 mod response;
 pub mod signed_url;
 pub mod signing;
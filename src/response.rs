@@ -14,14 +14,10 @@ where
                 .headers()
                 .get(http::header::CONTENT_TYPE)
                 .and_then(|ct| ct.to_str().ok())
+                && ct.starts_with("application/json")
+                && let Ok(api_err) = serde_json::from_slice::<error::ApiError>(resp.body().as_ref())
             {
-                if ct.starts_with("application/json") {
-                    if let Ok(api_err) =
-                        serde_json::from_slice::<error::ApiError>(resp.body().as_ref())
-                    {
-                        return Err(Error::Api(api_err));
-                    }
-                }
+                return Err(Error::Api(api_err));
             }
             Err(Error::from(resp.status()))
         }
@@ -41,6 +41,48 @@ pub(crate) const PATH_ENCODE_SET: &percent_encoding::AsciiSet = &QUERY_ENCODE_SE
     .add(b'%')
     .add(b'/');
 
+/// Appends the ACL `entity`, if any, and the query parameters of the
+/// `optional` struct to the URI of a bucket, object or default object ACL
+pub(crate) fn acl_uri<Q: serde::Serialize + Default>(
+    mut uri: String,
+    entity: Option<&str>,
+    optional: Option<Q>,
+) -> Result<String, crate::Error> {
+    if let Some(entity) = entity {
+        uri.push('/');
+        uri.extend(percent_encoding::percent_encode(
+            entity.as_bytes(),
+            PATH_ENCODE_SET,
+        ));
+    }
+
+    let query = optional.unwrap_or_default();
+    let query_params = serde_urlencoded::to_string(query)?;
+    if !query_params.is_empty() {
+        uri.push('?');
+        uri.push_str(&query_params);
+    }
+
+    Ok(uri)
+}
+
+/// Creates a request with an ACL entry as its JSON body
+pub(crate) fn acl_request(
+    method: &str,
+    uri: String,
+    acl: &crate::common::AccessControl,
+) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, crate::Error> {
+    let body = serde_json::to_vec(acl)?;
+    let len = body.len();
+
+    Ok(http::Request::builder()
+        .method(method)
+        .header("content-type", "application/json")
+        .header("content-length", len)
+        .uri(uri)
+        .body(std::io::Cursor::new(body))?)
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -1,2 +1,10 @@
+pub mod bucket_access_controls;
+pub mod buckets;
 pub mod common;
+pub mod default_object_access_controls;
+pub mod hmac_keys;
+pub mod iam;
+pub mod notifications;
+pub mod object_access_controls;
 pub mod objects;
+pub mod projects;
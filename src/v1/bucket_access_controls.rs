@@ -0,0 +1,175 @@
+//! Types and APIs for interacting with GCS [Bucket Access Controls](https://cloud.google.com/storage/docs/json_api/v1/bucketAccessControls)
+
+use crate::{
+    common::{
+        AccessControl, AccessControlResponse, DeleteAccessControlResponse,
+        ListAccessControlsResponse, StandardQueryParameters,
+    },
+    error::Error,
+    types::BucketName,
+};
+use http::uri::Authority;
+
+/// Helper struct used to collate all of the operations available for
+/// [Bucket Access Controls](https://cloud.google.com/storage/docs/json_api/v1/bucketAccessControls)
+/// Additionally, it can also be used to specify a custom authority.
+#[derive(Clone, Debug)]
+pub struct BucketAcl {
+    authority: Authority,
+}
+
+impl BucketAcl {
+    /// Supplies a custom HTTP authority, allowing a GCS host other than the
+    /// standard `storage.googleapis.com` to be used
+    pub fn with_authority(authority: Authority) -> Self {
+        Self { authority }
+    }
+}
+
+impl Default for BucketAcl {
+    /// Defaults to the standard GCS location `storage.googleapis.com`
+    fn default() -> Self {
+        Self {
+            authority: Authority::from_static("storage.googleapis.com"),
+        }
+    }
+}
+
+/// Optional parameters for all bucket access control operations
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketAccessControlOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub type ListBucketAccessControlsResponse = ListAccessControlsResponse;
+pub type GetBucketAccessControlResponse = AccessControlResponse;
+pub type InsertBucketAccessControlResponse = AccessControlResponse;
+pub type PatchBucketAccessControlResponse = AccessControlResponse;
+pub type UpdateBucketAccessControlResponse = AccessControlResponse;
+pub type DeleteBucketAccessControlResponse = DeleteAccessControlResponse;
+
+impl BucketAcl {
+    fn uri(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: Option<&str>,
+        optional: Option<BucketAccessControlOptional<'_>>,
+    ) -> Result<String, Error> {
+        let uri = format!(
+            "https://{}/storage/v1/b/{}/acl",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(bucket.as_ref(), crate::util::PATH_ENCODE_SET),
+        );
+
+        crate::util::acl_uri(uri, entity, optional)
+    }
+
+    /// Retrieves ACL entries on the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/bucketAccessControls/list)
+    pub fn list(
+        &self,
+        bucket: &BucketName<'_>,
+        optional: Option<BucketAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let uri = self.uri(bucket, None, optional)?;
+
+        Ok(http::Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+
+    /// Returns the ACL entry for the specified entity on the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/bucketAccessControls/get)
+    pub fn get(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: &str,
+        optional: Option<BucketAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let uri = self.uri(bucket, Some(entity), optional)?;
+
+        Ok(http::Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+
+    /// Creates a new ACL entry on the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`, `storage.buckets.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/bucketAccessControls/insert)
+    pub fn insert(
+        &self,
+        bucket: &BucketName<'_>,
+        acl: &AccessControl,
+        optional: Option<BucketAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        let uri = self.uri(bucket, None, optional)?;
+        crate::util::acl_request("POST", uri, acl)
+    }
+
+    /// Updates an ACL entry on the specified bucket, only the fields that are
+    /// set in `acl` are changed.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`, `storage.buckets.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/bucketAccessControls/patch)
+    pub fn patch(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: &str,
+        acl: &AccessControl,
+        optional: Option<BucketAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        let uri = self.uri(bucket, Some(entity), optional)?;
+        crate::util::acl_request("PATCH", uri, acl)
+    }
+
+    /// Replaces an ACL entry on the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`, `storage.buckets.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/bucketAccessControls/update)
+    pub fn update(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: &str,
+        acl: &AccessControl,
+        optional: Option<BucketAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        let uri = self.uri(bucket, Some(entity), optional)?;
+        crate::util::acl_request("PUT", uri, acl)
+    }
+
+    /// Permanently deletes the ACL entry for the specified entity on the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`, `storage.buckets.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/bucketAccessControls/delete)
+    pub fn delete(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: &str,
+        optional: Option<BucketAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let uri = self.uri(bucket, Some(entity), optional)?;
+
+        Ok(http::Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+}
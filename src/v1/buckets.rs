@@ -0,0 +1,380 @@
+//! Types and APIs for interacting with GCS [Buckets](https://cloud.google.com/storage/docs/json_api/v1/buckets)
+
+use crate::{
+    common::{AccessControl, Owner, StorageClass},
+    objects::Timestamp,
+};
+use http::uri::Authority;
+use std::collections::BTreeMap;
+
+mod delete;
+mod get;
+mod iam;
+mod insert;
+mod lifecycle;
+mod list;
+mod patch;
+mod update;
+
+pub use delete::*;
+pub use get::*;
+pub use iam::*;
+pub use insert::*;
+pub use lifecycle::*;
+pub use list::*;
+pub use patch::*;
+pub use update::*;
+
+/// Helper struct used to collate all of the operations available for
+/// [Buckets](https://cloud.google.com/storage/docs/json_api/v1/buckets)
+/// Additionally, it can also be used to specify a custom authority.
+#[derive(Clone, Debug)]
+pub struct Bucket {
+    authority: Authority,
+}
+
+impl Bucket {
+    /// Supplies a custom HTTP authority, allowing a GCS host other than the
+    /// standard `storage.googleapis.com` to be used
+    pub fn with_authority(authority: Authority) -> Self {
+        Self { authority }
+    }
+}
+
+impl Default for Bucket {
+    /// Defaults to the standard GCS location `storage.googleapis.com`
+    fn default() -> Self {
+        Self {
+            authority: Authority::from_static("storage.googleapis.com"),
+        }
+    }
+}
+
+/// [Metadata](https://cloud.google.com/storage/docs/json_api/v1/buckets#resource)
+/// associated with a Bucket.
+#[derive(Default, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketMetadata {
+    /// The ID of the bucket. For buckets, the `id` and `name` properties are the same.
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    /// The URI of this bucket.
+    #[serde(skip_serializing)]
+    pub self_link: Option<String>,
+    /// The project number of the project the bucket belongs to.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::from_str_opt"
+    )]
+    pub project_number: Option<u64>,
+    /// The name of the bucket. Required when inserting a bucket. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The creation time of the bucket in RFC 3339 format.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::timestamp_rfc3339_opt"
+    )]
+    pub time_created: Option<Timestamp>,
+    /// The modification time of the bucket metadata in RFC 3339 format.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::timestamp_rfc3339_opt"
+    )]
+    pub updated: Option<Timestamp>,
+    /// The metadata generation of this bucket.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::from_str_opt"
+    )]
+    pub metageneration: Option<i64>,
+    /// The [location](https://cloud.google.com/storage/docs/locations) of the
+    /// bucket. Object data for objects in the bucket resides in physical storage
+    /// within this region. Defaults to `US`. Can only be set when inserting a
+    /// bucket. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// The type of location that the bucket resides in, as determined by the
+    /// `location` property, eg. `region`, `dual-region` or `multi-region`.
+    #[serde(skip_serializing)]
+    pub location_type: Option<String>,
+    /// The bucket's default storage class, used whenever no `storage_class`
+    /// is specified for a newly-created object. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_class: Option<StorageClass>,
+    /// HTTP 1.1 Entity tag for the bucket.
+    #[serde(skip_serializing)]
+    pub etag: Option<String>,
+    /// Access controls on the bucket, only returned when using the `full`
+    /// projection. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acl: Option<Vec<AccessControl>>,
+    /// Default access controls to apply to new objects when no ACL is
+    /// provided, only returned when using the `full` projection. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_object_acl: Option<Vec<AccessControl>>,
+    /// The owner of the bucket. This is always the project team's owner group.
+    #[serde(skip_serializing)]
+    pub owner: Option<Owner>,
+    /// Whether or not to automatically apply an eventBasedHold to new objects
+    /// added to the bucket. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_event_based_hold: Option<bool>,
+    /// User-provided bucket labels, in key/value pairs. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<BTreeMap<String, String>>,
+    /// The bucket's versioning configuration. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub versioning: Option<Versioning>,
+    /// The bucket's lifecycle configuration. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle: Option<Lifecycle>,
+    /// The bucket's Cross-Origin Resource Sharing (CORS) configuration. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cors: Option<Vec<Cors>>,
+    /// The bucket's retention policy, which defines the minimum age an object
+    /// in the bucket must reach before it can be deleted or replaced. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention_policy: Option<RetentionPolicy>,
+    /// The bucket's IAM configuration. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iam_configuration: Option<IamConfiguration>,
+    /// Encryption configuration for the bucket. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<Encryption>,
+    /// The bucket's logging configuration, which defines the destination
+    /// bucket and optional name prefix for the current bucket's logs. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<Logging>,
+    /// The bucket's website configuration, controlling how the service behaves
+    /// when accessing bucket contents as a web site. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<Website>,
+    /// The bucket's billing configuration. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing: Option<Billing>,
+    /// The bucket's soft delete policy, which defines the period of time that
+    /// soft-deleted objects will be retained, and cannot be permanently
+    /// deleted. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soft_delete_policy: Option<SoftDeletePolicy>,
+    /// The bucket's Autoclass configuration. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autoclass: Option<Autoclass>,
+    /// The bucket's hierarchical namespace configuration. Can only be set when
+    /// inserting a bucket. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hierarchical_namespace: Option<HierarchicalNamespace>,
+}
+
+/// The bucket's [versioning](https://cloud.google.com/storage/docs/object-versioning)
+/// configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Versioning {
+    /// While set to true, versioning is fully enabled for this bucket.
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// A single [CORS](https://cloud.google.com/storage/docs/cross-origin)
+/// configuration entry.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Cors {
+    /// The list of Origins eligible to receive CORS response headers. Note:
+    /// `*` is permitted in the list of origins, and means "any Origin".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub origin: Vec<String>,
+    /// The list of HTTP methods on which to include CORS response headers,
+    /// (`GET`, `OPTIONS`, `POST`, etc) Note: `*` is permitted in the list of
+    /// methods, and means "any method".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub method: Vec<String>,
+    /// The list of HTTP headers other than the simple response headers to give
+    /// permission for the user-agent to share across domains.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub response_header: Vec<String>,
+    /// The value, in seconds, to return in the `Access-Control-Max-Age` header
+    /// used in preflight responses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age_seconds: Option<u32>,
+}
+
+/// The bucket's [retention policy](https://cloud.google.com/storage/docs/bucket-lock).
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPolicy {
+    /// The duration in seconds that objects need to be retained. Retention
+    /// duration must be greater than zero and less than 100 years.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::objects::to_str_opt",
+        deserialize_with = "crate::objects::from_str_opt"
+    )]
+    pub retention_period: Option<u64>,
+    /// Server-determined value that indicates the time from which policy was
+    /// enforced and effective.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::timestamp_rfc3339_opt"
+    )]
+    pub effective_time: Option<Timestamp>,
+    /// Once locked, an object retention policy cannot be modified.
+    #[serde(skip_serializing)]
+    pub is_locked: Option<bool>,
+}
+
+/// The bucket's [IAM](https://cloud.google.com/storage/docs/access-control/iam)
+/// configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IamConfiguration {
+    /// The bucket's [uniform bucket-level access](https://cloud.google.com/storage/docs/uniform-bucket-level-access)
+    /// configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uniform_bucket_level_access: Option<UniformBucketLevelAccess>,
+    /// The bucket's [public access prevention](https://cloud.google.com/storage/docs/public-access-prevention)
+    /// status, either `inherited` or `enforced`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_access_prevention: Option<String>,
+}
+
+/// The bucket's uniform bucket-level access configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UniformBucketLevelAccess {
+    /// If set, access checks only use bucket-level IAM policies or above.
+    #[serde(default)]
+    pub enabled: bool,
+    /// The deadline time for changing `enabled` from true to false, after
+    /// which uniform bucket-level access is locked.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::timestamp_rfc3339_opt"
+    )]
+    pub locked_time: Option<Timestamp>,
+}
+
+/// The bucket's default [encryption](https://cloud.google.com/storage/docs/encryption)
+/// configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Encryption {
+    /// A Cloud KMS key that will be used to encrypt objects inserted into
+    /// this bucket, if no encryption method is specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_kms_key_name: Option<String>,
+}
+
+/// The bucket's [usage logging](https://cloud.google.com/storage/docs/access-logs)
+/// configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Logging {
+    /// The destination bucket where the current bucket's logs should be placed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_bucket: Option<String>,
+    /// A prefix for log object names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_object_prefix: Option<String>,
+}
+
+/// The bucket's [static website](https://cloud.google.com/storage/docs/static-website)
+/// configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Website {
+    /// If the requested object path is missing, the service will ensure the
+    /// path has a trailing '/', append this suffix, and attempt to retrieve
+    /// the resulting object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub main_page_suffix: Option<String>,
+    /// If the requested object path is missing, and any `main_page_suffix`
+    /// object is missing, if applicable, the service will return the named
+    /// object from this bucket as the content for a 404 Not Found result.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_found_page: Option<String>,
+}
+
+/// The bucket's billing configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Billing {
+    /// When set to true, [Requester Pays](https://cloud.google.com/storage/docs/requester-pays)
+    /// is enabled for this bucket.
+    #[serde(default)]
+    pub requester_pays: bool,
+}
+
+/// The bucket's [soft delete](https://cloud.google.com/storage/docs/soft-delete)
+/// policy.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SoftDeletePolicy {
+    /// The period of time in seconds that soft-deleted objects in the bucket
+    /// will be retained and cannot be permanently deleted. A value of `0`
+    /// disables soft delete.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::objects::to_str_opt",
+        deserialize_with = "crate::objects::from_str_opt"
+    )]
+    pub retention_duration_seconds: Option<u64>,
+    /// Server-determined value that indicates the time from which the policy,
+    /// or one with a greater retention, was effective.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::timestamp_rfc3339_opt"
+    )]
+    pub effective_time: Option<Timestamp>,
+}
+
+/// The bucket's [Autoclass](https://cloud.google.com/storage/docs/autoclass)
+/// configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Autoclass {
+    /// Whether or not Autoclass is enabled on this bucket.
+    #[serde(default)]
+    pub enabled: bool,
+    /// The time at which Autoclass was last enabled or disabled for this bucket.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::timestamp_rfc3339_opt"
+    )]
+    pub toggle_time: Option<Timestamp>,
+    /// The storage class that objects in the bucket eventually transition to
+    /// if they are not read for a certain length of time. Valid values are
+    /// `NEARLINE` and `ARCHIVE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_storage_class: Option<StorageClass>,
+    /// The time at which the Autoclass terminal storage class was last updated
+    /// for this bucket.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::timestamp_rfc3339_opt"
+    )]
+    pub terminal_storage_class_update_time: Option<Timestamp>,
+}
+
+/// The bucket's [hierarchical namespace](https://cloud.google.com/storage/docs/hns-overview)
+/// configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HierarchicalNamespace {
+    /// When set to true, hierarchical namespace is enabled for this bucket.
+    #[serde(default)]
+    pub enabled: bool,
+}
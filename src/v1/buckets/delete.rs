@@ -0,0 +1,71 @@
+use crate::{
+    common::{MetagenerationConditionals, StandardQueryParameters},
+    error::Error,
+    response::ApiResponse,
+    types::BucketName,
+};
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteBucketOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    #[serde(flatten)]
+    pub conditionals: MetagenerationConditionals,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub struct DeleteBucketResponse;
+
+impl ApiResponse<&[u8]> for DeleteBucketResponse {}
+impl ApiResponse<bytes::Bytes> for DeleteBucketResponse {}
+
+impl<B> TryFrom<http::Response<B>> for DeleteBucketResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        if response.status() == http::StatusCode::NO_CONTENT {
+            Ok(Self)
+        } else {
+            Err(Self::Error::from(response.status()))
+        }
+    }
+}
+
+impl super::Bucket {
+    /// Permanently deletes an empty bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.delete`
+    ///
+    /// [Complete API documentation](https://cloud.google.com/storage/docs/json_api/v1/buckets/delete)
+    pub fn delete(
+        &self,
+        bucket: &BucketName<'_>,
+        optional: Option<DeleteBucketOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = format!(
+            "https://{}/storage/v1/b/{}",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(bucket.as_ref(), crate::util::PATH_ENCODE_SET),
+        );
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder
+            .method("DELETE")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+}
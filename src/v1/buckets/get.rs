@@ -0,0 +1,71 @@
+use crate::{
+    common::{MetagenerationConditionals, Projection, StandardQueryParameters},
+    error::Error,
+    response::ApiResponse,
+    types::BucketName,
+};
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBucketOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    #[serde(flatten)]
+    pub conditionals: MetagenerationConditionals,
+    /// Set of properties to return. Defaults to `noAcl`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Projection>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub struct GetBucketResponse {
+    pub metadata: super::BucketMetadata,
+}
+
+impl ApiResponse<&[u8]> for GetBucketResponse {}
+impl ApiResponse<bytes::Bytes> for GetBucketResponse {}
+
+impl<B> TryFrom<http::Response<B>> for GetBucketResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let metadata: super::BucketMetadata = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { metadata })
+    }
+}
+
+impl super::Bucket {
+    /// Returns metadata for the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.get`, `storage.buckets.getIamPolicy`*
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/buckets/get)
+    pub fn get(
+        &self,
+        bucket: &BucketName<'_>,
+        optional: Option<GetBucketOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = format!(
+            "https://{}/storage/v1/b/{}",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(bucket.as_ref(), crate::util::PATH_ENCODE_SET),
+        );
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
+    }
+}
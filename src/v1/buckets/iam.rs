@@ -0,0 +1,124 @@
+use crate::{common::StandardQueryParameters, error::Error, iam::Policy, types::BucketName};
+
+/// Optional parameters when getting a bucket's IAM policy
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBucketIamPolicyOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// The IAM policy format version to be returned. If the policy has
+    /// conditional bindings this must be set to
+    /// [`CONDITIONAL_POLICY_VERSION`](crate::iam::CONDITIONAL_POLICY_VERSION),
+    /// otherwise the request fails.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options_requested_policy_version: Option<u32>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+/// Optional parameters when setting or testing a bucket's IAM policy
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketIamOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+impl super::Bucket {
+    fn iam_uri(&self, bucket: &BucketName<'_>, suffix: &str) -> String {
+        format!(
+            "https://{}/storage/v1/b/{}/iam{suffix}",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(bucket.as_ref(), crate::util::PATH_ENCODE_SET),
+        )
+    }
+
+    /// Returns an IAM policy for the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/buckets/getIamPolicy)
+    pub fn get_iam_policy(
+        &self,
+        bucket: &BucketName<'_>,
+        optional: Option<GetBucketIamPolicyOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = self.iam_uri(bucket, "");
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
+    }
+
+    /// Updates an IAM policy for the specified bucket. The policy's etag should
+    /// be the one retrieved via [`get_iam_policy`](#method.get_iam_policy) so
+    /// that concurrent modifications of the policy are detected.
+    ///
+    /// Required IAM Permissions: `storage.buckets.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/buckets/setIamPolicy)
+    pub fn set_iam_policy(
+        &self,
+        bucket: &BucketName<'_>,
+        policy: &Policy,
+        optional: Option<BucketIamOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        let mut uri = self.iam_uri(bucket, "");
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        let body = serde_json::to_vec(policy)?;
+        let len = body.len();
+        let body = std::io::Cursor::new(body);
+
+        Ok(req_builder
+            .method("PUT")
+            .header("content-type", "application/json")
+            .header("content-length", len)
+            .uri(uri)
+            .body(body)?)
+    }
+
+    /// Tests a set of permissions on the given bucket to see which, if any,
+    /// are held by the caller.
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/buckets/testIamPermissions)
+    pub fn test_iam_permissions(
+        &self,
+        bucket: &BucketName<'_>,
+        permissions: &[&str],
+        optional: Option<BucketIamOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = self.iam_uri(bucket, "/testPermissions");
+        crate::iam::push_permissions(&mut uri, permissions)?;
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('&');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
+    }
+}
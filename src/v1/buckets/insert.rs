@@ -0,0 +1,100 @@
+use crate::{
+    common::{PredefinedAcl, PredefinedBucketAcl, Projection, StandardQueryParameters},
+    error::Error,
+    response::ApiResponse,
+    types::BucketName,
+};
+
+/// Optional parameters when inserting a bucket.
+/// See [here](https://cloud.google.com/storage/docs/json_api/v1/buckets/insert#parameters)
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsertBucketOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// Apply a predefined set of access controls to this bucket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predefined_acl: Option<PredefinedBucketAcl>,
+    /// Apply a predefined set of default object access controls to this bucket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predefined_default_object_acl: Option<PredefinedAcl>,
+    /// Set of properties to return. Defaults to `noAcl`, unless the bucket
+    /// resource specifies acl or defaultObjectAcl properties, when it defaults to full.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Projection>,
+    /// The project to be billed for this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+/// The response from an [`insert`](#method.insert) request is the bucket
+/// [metadata](https://cloud.google.com/storage/docs/json_api/v1/buckets#resource)
+/// for the newly created bucket.
+pub struct InsertBucketResponse {
+    pub metadata: super::BucketMetadata,
+}
+
+impl ApiResponse<&[u8]> for InsertBucketResponse {}
+impl ApiResponse<bytes::Bytes> for InsertBucketResponse {}
+
+impl<B> TryFrom<http::Response<B>> for InsertBucketResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let metadata: super::BucketMetadata = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { metadata })
+    }
+}
+
+impl super::Bucket {
+    /// Creates a new bucket in the specified project.
+    ///
+    /// **NOTE**: You **must** specify the `name` field in the metadata provided
+    /// to this function with a valid bucket name.
+    ///
+    /// Required IAM Permissions: `storage.buckets.create`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/buckets/insert)
+    pub fn insert(
+        &self,
+        project: &str,
+        metadata: &super::BucketMetadata,
+        optional: Option<InsertBucketOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        let name = metadata
+            .name
+            .as_deref()
+            .ok_or(Error::MissingMetadata("name"))?;
+        BucketName::try_from(name)?;
+
+        let mut uri = format!(
+            "https://{}/storage/v1/b?project={}",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(project.as_bytes(), crate::util::QUERY_ENCODE_SET),
+        );
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('&');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        let md = serde_json::to_vec(&metadata)?;
+        let len = md.len();
+        let md = std::io::Cursor::new(md);
+
+        Ok(req_builder
+            .method("POST")
+            .header("content-type", "application/json")
+            .header("content-length", len)
+            .uri(uri)
+            .body(md)?)
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{
+    common::StorageClass,
+    objects::{Metadata, Timestamp},
+};
+
+/// The bucket's [lifecycle](https://cloud.google.com/storage/docs/lifecycle)
+/// configuration.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Lifecycle {
+    /// A lifecycle management rule, which is made of an action to take and
+    /// the condition(s) under which the action will be taken.
+    #[serde(default)]
+    pub rule: Vec<LifecycleRule>,
+}
+
+impl Lifecycle {
+    /// Appends a rule to the lifecycle configuration
+    pub fn with_rule(mut self, rule: LifecycleRule) -> Self {
+        self.rule.push(rule);
+        self
+    }
+}
+
+/// A single lifecycle management rule
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleRule {
+    /// The action to take.
+    pub action: Action,
+    /// The condition(s) under which the action will be taken.
+    #[serde(default)]
+    pub condition: Condition,
+}
+
+impl LifecycleRule {
+    /// Creates a rule that takes the specified action, with no conditions.
+    /// Note that GCS requires at least one condition to be set on each rule.
+    pub fn new(action: Action) -> Self {
+        Self {
+            action,
+            condition: Condition::default(),
+        }
+    }
+
+    /// Creates a rule that deletes objects
+    pub fn delete() -> Self {
+        Self::new(Action::Delete)
+    }
+
+    /// Creates a rule that changes the storage class of objects
+    pub fn set_storage_class(storage_class: StorageClass) -> Self {
+        Self::new(Action::SetStorageClass { storage_class })
+    }
+
+    /// Creates a rule that aborts incomplete XML API multipart uploads
+    pub fn abort_incomplete_multipart_upload() -> Self {
+        Self::new(Action::AbortIncompleteMultipartUpload)
+    }
+
+    /// Sets [`Condition::age`]
+    pub fn age(mut self, days: u32) -> Self {
+        self.condition.age = Some(days);
+        self
+    }
+
+    /// Sets [`Condition::created_before`]
+    pub fn created_before(mut self, date: time::Date) -> Self {
+        self.condition.created_before = Some(date);
+        self
+    }
+
+    /// Sets [`Condition::custom_time_before`]
+    pub fn custom_time_before(mut self, date: time::Date) -> Self {
+        self.condition.custom_time_before = Some(date);
+        self
+    }
+
+    /// Sets [`Condition::days_since_custom_time`]
+    pub fn days_since_custom_time(mut self, days: u32) -> Self {
+        self.condition.days_since_custom_time = Some(days);
+        self
+    }
+
+    /// Sets [`Condition::days_since_noncurrent_time`]
+    pub fn days_since_noncurrent_time(mut self, days: u32) -> Self {
+        self.condition.days_since_noncurrent_time = Some(days);
+        self
+    }
+
+    /// Sets [`Condition::is_live`]
+    pub fn is_live(mut self, is_live: bool) -> Self {
+        self.condition.is_live = Some(is_live);
+        self
+    }
+
+    /// Adds a prefix to [`Condition::matches_prefix`]
+    pub fn matches_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.condition.matches_prefix.push(prefix.into());
+        self
+    }
+
+    /// Adds a suffix to [`Condition::matches_suffix`]
+    pub fn matches_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.condition.matches_suffix.push(suffix.into());
+        self
+    }
+
+    /// Adds a storage class to [`Condition::matches_storage_class`]
+    pub fn matches_storage_class(mut self, storage_class: StorageClass) -> Self {
+        self.condition.matches_storage_class.push(storage_class);
+        self
+    }
+
+    /// Sets [`Condition::noncurrent_time_before`]
+    pub fn noncurrent_time_before(mut self, date: time::Date) -> Self {
+        self.condition.noncurrent_time_before = Some(date);
+        self
+    }
+
+    /// Sets [`Condition::num_newer_versions`]
+    pub fn num_newer_versions(mut self, versions: u32) -> Self {
+        self.condition.num_newer_versions = Some(versions);
+        self
+    }
+}
+
+/// The [action](https://cloud.google.com/storage/docs/lifecycle#actions) to
+/// take when a [`LifecycleRule`]'s conditions are met
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum Action {
+    /// Deletes the object
+    Delete,
+    /// Changes the storage class of the object
+    #[serde(rename_all = "camelCase")]
+    SetStorageClass {
+        /// The new storage class of the object
+        storage_class: StorageClass,
+    },
+    /// Aborts an incomplete XML API multipart upload
+    AbortIncompleteMultipartUpload,
+}
+
+/// The [conditions](https://cloud.google.com/storage/docs/lifecycle#conditions)
+/// under which a lifecycle [`Action`] will be taken. All of the specified
+/// conditions must be met for the action to be taken.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Condition {
+    /// Age of an object (in days). This condition is satisfied when an object
+    /// reaches the specified age.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<u32>,
+    /// This condition is satisfied when an object is created before midnight
+    /// of the specified date in UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "date_opt")]
+    pub created_before: Option<time::Date>,
+    /// This condition is satisfied when the custom time on an object is before
+    /// this date in UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "date_opt")]
+    pub custom_time_before: Option<time::Date>,
+    /// Number of days elapsed since the user-specified timestamp set on an
+    /// object. The condition is satisfied if the days elapsed is at least this
+    /// number. If no custom timestamp is specified on an object, the condition
+    /// does not apply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_since_custom_time: Option<u32>,
+    /// Number of days elapsed since the noncurrent timestamp of an object.
+    /// The condition is satisfied if the days elapsed is at least this number.
+    /// This condition is relevant only for versioned objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_since_noncurrent_time: Option<u32>,
+    /// Relevant only for versioned objects. If the value is `true`, this
+    /// condition matches live objects; if the value is `false`, it matches
+    /// archived objects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_live: Option<bool>,
+    /// List of object name prefixes. This condition will be satisfied when at
+    /// least one of the prefixes exactly matches the beginning of the object name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub matches_prefix: Vec<String>,
+    /// List of object name suffixes. This condition will be satisfied when at
+    /// least one of the suffixes exactly matches the end of the object name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub matches_suffix: Vec<String>,
+    /// Objects having any of the storage classes specified by this condition
+    /// will be matched.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub matches_storage_class: Vec<StorageClass>,
+    /// This condition is relevant only for versioned objects. An object version
+    /// satisfies this condition only if it became noncurrent before the
+    /// specified date in UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "date_opt")]
+    pub noncurrent_time_before: Option<time::Date>,
+    /// Relevant only for versioned objects. If the value is N, this condition
+    /// is satisfied when there are at least N versions (including the live
+    /// version) newer than this version of the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_newer_versions: Option<u32>,
+}
+
+impl Condition {
+    /// Checks whether all of the specified conditions are met by the object.
+    /// `newer_versions` is the number of versions of the object that are newer
+    /// than this one, if known.
+    ///
+    /// Any condition that can't be determined from the available information,
+    /// eg. `age` when the object has no `time_created`, is considered unmet.
+    fn is_met(&self, md: &Metadata, now: Timestamp, newer_versions: Option<u32>) -> bool {
+        fn days_since(ts: Option<Timestamp>, now: Timestamp, days: u32) -> bool {
+            ts.is_some_and(|ts| (now - ts).whole_days() >= i64::from(days))
+        }
+
+        fn before(ts: Option<Timestamp>, date: time::Date) -> bool {
+            ts.is_some_and(|ts| ts.to_offset(time::UtcOffset::UTC).date() < date)
+        }
+
+        // An object version becomes noncurrent when it is deleted or replaced,
+        // in which case a listing of every version also has newer versions
+        let noncurrent_time = md.time_deleted;
+        let is_live = noncurrent_time.is_none() && newer_versions.is_none_or(|newer| newer == 0);
+        let name = md.name.as_deref();
+
+        self.age
+            .is_none_or(|age| days_since(md.time_created, now, age))
+            && self
+                .created_before
+                .is_none_or(|date| before(md.time_created, date))
+            && self
+                .custom_time_before
+                .is_none_or(|date| before(md.custom_time, date))
+            && self
+                .days_since_custom_time
+                .is_none_or(|days| days_since(md.custom_time, now, days))
+            && self
+                .days_since_noncurrent_time
+                .is_none_or(|days| days_since(noncurrent_time, now, days))
+            && self.is_live.is_none_or(|live| live == is_live)
+            && (self.matches_prefix.is_empty()
+                || name.is_some_and(|name| {
+                    self.matches_prefix
+                        .iter()
+                        .any(|prefix| name.starts_with(prefix.as_str()))
+                }))
+            && (self.matches_suffix.is_empty()
+                || name.is_some_and(|name| {
+                    self.matches_suffix
+                        .iter()
+                        .any(|suffix| name.ends_with(suffix.as_str()))
+                }))
+            && (self.matches_storage_class.is_empty()
+                || md
+                    .storage_class
+                    .is_some_and(|sc| self.matches_storage_class.contains(&sc)))
+            && self
+                .noncurrent_time_before
+                .is_none_or(|date| before(noncurrent_time, date))
+            && self
+                .num_newer_versions
+                .is_none_or(|versions| newer_versions.is_some_and(|newer| newer >= versions))
+    }
+}
+
+/// The relative at-rest cost of a storage class, used to determine which
+/// `SetStorageClass` action takes precedence
+fn storage_cost(storage_class: StorageClass) -> u8 {
+    match storage_class {
+        StorageClass::Archive => 0,
+        StorageClass::Coldline => 1,
+        StorageClass::Nearline => 2,
+        StorageClass::MultiRegional
+        | StorageClass::Regional
+        | StorageClass::Standard
+        | StorageClass::DurableReducedAvailability => 3,
+    }
+}
+
+fn evaluate_inner<'l>(
+    lifecycle: &'l Lifecycle,
+    md: &Metadata,
+    now: Timestamp,
+    newer_versions: Option<u32>,
+) -> Option<&'l Action> {
+    lifecycle
+        .rule
+        .iter()
+        .filter(|rule| match rule.action {
+            Action::Delete => true,
+            // Changing to the class the object already has is a noop
+            Action::SetStorageClass { storage_class } => md.storage_class != Some(storage_class),
+            // Only applies to XML API multipart uploads, never to objects
+            Action::AbortIncompleteMultipartUpload => false,
+        })
+        .filter(|rule| rule.condition.is_met(md, now, newer_versions))
+        .map(|rule| &rule.action)
+        // Delete takes precedence over any SetStorageClass action, and the
+        // SetStorageClass action that switches to the storage class with the
+        // lowest at-rest pricing takes precedence over other SetStorageClass
+        // actions
+        .min_by_key(|action| match action {
+            Action::SetStorageClass { storage_class } => 1 + storage_cost(*storage_class),
+            _ => 0,
+        })
+}
+
+/// Locally evaluates which [`Action`], if any, the lifecycle configuration
+/// would take on the object at the time `now`, following the same
+/// [precedence rules](https://cloud.google.com/storage/docs/lifecycle#expiration_time)
+/// as GCS.
+///
+/// This only requires the object's metadata, so conditions that depend on
+/// other versions of the object, ie. `num_newer_versions`, are never met. Use
+/// [`evaluate_listing`] to evaluate every version of objects in a listing.
+pub fn evaluate<'l>(lifecycle: &'l Lifecycle, md: &Metadata, now: Timestamp) -> Option<&'l Action> {
+    evaluate_inner(lifecycle, md, now, None)
+}
+
+/// Locally evaluates which [`Action`], if any, the lifecycle configuration
+/// would take on each object in a listing at the time `now`. The returned
+/// actions are in the same order as `objects`.
+///
+/// If the listing includes all versions of the objects, as when listing with
+/// `versions` set, the `num_newer_versions` condition is evaluated by
+/// counting the number of versions with a greater generation.
+pub fn evaluate_listing<'l>(
+    lifecycle: &'l Lifecycle,
+    objects: &[Metadata],
+    now: Timestamp,
+) -> Vec<Option<&'l Action>> {
+    // The sorted generations of every version of each object in the listing
+    let mut generations = std::collections::HashMap::<Option<&str>, Vec<i64>>::new();
+    for md in objects {
+        if let Some(generation) = md.generation {
+            generations
+                .entry(md.name.as_deref())
+                .or_default()
+                .push(generation);
+        }
+    }
+    for versions in generations.values_mut() {
+        versions.sort_unstable();
+    }
+
+    objects
+        .iter()
+        .map(|md| {
+            let newer_versions = md.generation.map(|generation| {
+                let versions = &generations[&md.name.as_deref()];
+                let newer = versions.len() - versions.partition_point(|g| *g <= generation);
+                u32::try_from(newer).unwrap_or(u32::MAX)
+            });
+
+            evaluate_inner(lifecycle, md, now, newer_versions)
+        })
+        .collect()
+}
+
+/// (De)serializes dates in the `YYYY-MM-DD` form used by lifecycle conditions
+mod date_opt {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S>(date: &Option<time::Date>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(date) => serializer.collect_str(&format_args!(
+                "{:04}-{:02}-{:02}",
+                date.year(),
+                date.month() as u8,
+                date.day()
+            )),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Option<time::Date>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let date_str: &str = Deserialize::deserialize(deserializer)?;
+        super::parse_date(date_str)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid date '{date_str}'")))
+            .map(Some)
+    }
+}
+
+fn parse_date(date: &str) -> Option<time::Date> {
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month: u8 = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+
+    time::Date::from_calendar_date(year, month.try_into().ok()?, day).ok()
+}
@@ -0,0 +1,99 @@
+use crate::{
+    common::{Projection, StandardQueryParameters},
+    error::Error,
+    response::ApiResponse,
+};
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListBucketsOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// Maximum number of buckets to return in a single response. The service
+    /// will use this parameter or 1,000 items, whichever is smaller.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<u32>,
+    /// A previously-returned page token representing part of the larger
+    /// set of results to view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_token: Option<&'a str>,
+    /// Filter results to buckets whose names begin with this prefix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<&'a str>,
+    /// Set of properties to return. Defaults to `noAcl`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Projection>,
+    /// The project to be billed for this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub struct ListBucketsResponse {
+    /// The list of buckets
+    pub buckets: Vec<super::BucketMetadata>,
+    /// The continuation token, included only if there are more items to return.
+    /// Provide this value as the `page_token` of a subsequent request in order
+    /// to return the next page of results.
+    pub page_token: Option<String>,
+}
+
+impl ApiResponse<&[u8]> for ListBucketsResponse {}
+impl ApiResponse<bytes::Bytes> for ListBucketsResponse {}
+
+impl<B> TryFrom<http::Response<B>> for ListBucketsResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RawListResponse {
+            next_page_token: Option<String>,
+            // This field won't be present if the list doesn't actually
+            // return any items
+            #[serde(default)]
+            items: Vec<super::BucketMetadata>,
+        }
+
+        let res: RawListResponse = serde_json::from_slice(body.as_ref())?;
+
+        Ok(Self {
+            buckets: res.items,
+            page_token: res.next_page_token,
+        })
+    }
+}
+
+impl super::Bucket {
+    /// Retrieves a list of buckets for a given project.
+    ///
+    /// Required IAM Permissions: `storage.buckets.list`, `storage.buckets.getIamPolicy`*
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/buckets/list)
+    pub fn list(
+        &self,
+        project: &str,
+        optional: Option<ListBucketsOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = format!(
+            "https://{}/storage/v1/b?project={}",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(project.as_bytes(), crate::util::QUERY_ENCODE_SET),
+        );
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('&');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    common::{
+        MetagenerationConditionals, PredefinedAcl, PredefinedBucketAcl, Projection,
+        StandardQueryParameters,
+    },
+    error::Error,
+    response::ApiResponse,
+    types::BucketName,
+};
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBucketOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    #[serde(flatten)]
+    pub conditionals: MetagenerationConditionals,
+    /// Apply a predefined set of access controls to this bucket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predefined_acl: Option<PredefinedBucketAcl>,
+    /// Apply a predefined set of default object access controls to this bucket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predefined_default_object_acl: Option<PredefinedAcl>,
+    /// Set of properties to return. Defaults to `full`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Projection>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub struct UpdateBucketResponse {
+    pub metadata: super::BucketMetadata,
+}
+
+impl ApiResponse<&[u8]> for UpdateBucketResponse {}
+impl ApiResponse<bytes::Bytes> for UpdateBucketResponse {}
+
+impl<B> TryFrom<http::Response<B>> for UpdateBucketResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let metadata: super::BucketMetadata = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { metadata })
+    }
+}
+
+impl super::Bucket {
+    /// Updates a bucket, replacing all of its writable metadata. Any writable
+    /// metadata that is not specified in `metadata` is removed from the bucket.
+    /// Changes made to the bucket may take time to propagate to objects in the
+    /// bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.get`, `storage.buckets.update`
+    ///
+    /// [Complete API documentation](https://cloud.google.com/storage/docs/json_api/v1/buckets/update)
+    pub fn update(
+        &self,
+        bucket: &BucketName<'_>,
+        metadata: &super::BucketMetadata,
+        optional: Option<UpdateBucketOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        let mut uri = format!(
+            "https://{}/storage/v1/b/{}",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(bucket.as_ref(), crate::util::PATH_ENCODE_SET),
+        );
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        let md = serde_json::to_vec(&metadata)?;
+        let len = md.len();
+        let md = std::io::Cursor::new(md);
+
+        Ok(req_builder
+            .method("PUT")
+            .header("content-type", "application/json")
+            .header("content-length", len)
+            .uri(uri)
+            .body(md)?)
+    }
+}
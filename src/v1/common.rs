@@ -9,7 +9,7 @@ fn pretty_on(pretty_print: &bool) -> bool {
 
 /// [Standard Query Parameters](https://cloud.google.com/storage/docs/json_api/v1/parameters#query)
 /// can be used in almost any API request to GCS
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct StandardQueryParameters<'a> {
     /// Selector specifying a subset of fields to include in the response,
@@ -64,6 +64,22 @@ pub struct Conditionals {
     pub if_metageneration_not_match: Option<i64>,
 }
 
+/// Contains the conditionals that determine whether an operation on a
+/// resource without generations, such as a bucket, will actually proceed
+/// or not
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetagenerationConditionals {
+    /// Makes the operation conditional on whether the resource's current
+    /// metageneration matches the given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_metageneration_match: Option<i64>,
+    /// Makes the operation conditional on whether the resource's current
+    /// metageneration does not match the given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_metageneration_not_match: Option<i64>,
+}
+
 /// [Storage classes](https://cloud.google.com/storage/docs/storage-classes)
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -88,6 +104,10 @@ pub enum StorageClass {
     /// and disaster recovery. Unlike other "cold" storage services, your data is available
     /// within milliseconds, not hours or days.
     Coldline,
+    /// [Archive Storage](https://cloud.google.com/storage/docs/storage-classes#archive)
+    /// is the lowest-cost, highly durable storage service for data archiving, online
+    /// backup, and disaster recovery. It has a 365-day minimum storage duration.
+    Archive,
     /// Users that create a bucket without specifying a default storage class see the bucket's
     /// default storage class listed as [Standard Storage](https://cloud.google.com/storage/docs/storage-classes#standard)
     /// in the API. Objects created without a storage class in such a bucket are also listed
@@ -131,6 +151,24 @@ pub enum PredefinedAcl {
     PublicRead,
 }
 
+/// A [predefined or "canned" ACL](https://cloud.google.com/storage/docs/access-control/lists#predefined-acl)
+/// that can be applied to a bucket. Note that the default object ACL of a bucket
+/// uses [`PredefinedAcl`] instead.
+#[derive(Serialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum PredefinedBucketAcl {
+    /// Project team owners get OWNER access, and allAuthenticatedUsers get READER access.
+    AuthenticatedRead,
+    /// Project team owners get OWNER access.
+    Private,
+    /// Project team members get access according to their roles.
+    ProjectPrivate,
+    /// Project team owners get OWNER access, and allUsers get READER access.
+    PublicRead,
+    /// Project team owners get OWNER access, and allUsers get WRITER access.
+    PublicReadWrite,
+}
+
 /// Set of properties to return. Defaults to `NoAcl`.
 #[derive(Serialize, Copy, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
@@ -141,3 +179,195 @@ pub enum Projection {
     #[default]
     NoAcl,
 }
+
+/// The access permission granted to an entity by an [`AccessControl`]
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Role {
+    /// For objects, allows the entity to read the object data and metadata.
+    /// For buckets, allows the entity to list the bucket's contents and read
+    /// the bucket's metadata, excluding ACLs.
+    Reader,
+    /// Only applicable to buckets, allows the entity to list, create,
+    /// overwrite and delete objects in the bucket. Object and default object
+    /// ACL entries with this role are rejected with [`crate::Error::InvalidObjectRole`].
+    Writer,
+    /// Allows the entity full control over the object or bucket, including
+    /// reading and changing ACLs.
+    Owner,
+}
+
+/// The project team associated with an entity, if any
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTeam {
+    /// The project number.
+    pub project_number: Option<String>,
+    /// The team, one of `owners`, `editors` or `viewers`.
+    pub team: Option<String>,
+}
+
+/// An [access-control entry](https://cloud.google.com/storage/docs/access-control/lists)
+/// for a bucket, an object, or the default object ACL of a bucket.
+#[derive(Default, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessControl {
+    /// The ID of the access-control entry.
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    /// The link to this access-control entry.
+    #[serde(skip_serializing)]
+    pub self_link: Option<String>,
+    /// The name of the bucket.
+    #[serde(skip_serializing)]
+    pub bucket: Option<String>,
+    /// The name of the object, if applied to an object.
+    #[serde(skip_serializing)]
+    pub object: Option<String>,
+    /// The content generation of the object, if applied to an object.
+    #[serde(
+        default,
+        skip_serializing,
+        deserialize_with = "crate::objects::from_str_opt"
+    )]
+    pub generation: Option<i64>,
+    /// The entity holding the permission, in one of the following forms:
+    ///
+    /// * `user-{userId}`
+    /// * `user-{email}`
+    /// * `group-{groupId}`
+    /// * `group-{email}`
+    /// * `domain-{domain}`
+    /// * `project-{team}-{projectNumber}`
+    /// * `allUsers`
+    /// * `allAuthenticatedUsers`
+    ///
+    /// **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<String>,
+    /// The access permission for the entity. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<Role>,
+    /// The email address associated with the entity, if any.
+    #[serde(skip_serializing)]
+    pub email: Option<String>,
+    /// The ID for the entity, if any.
+    #[serde(skip_serializing)]
+    pub entity_id: Option<String>,
+    /// The domain associated with the entity, if any.
+    #[serde(skip_serializing)]
+    pub domain: Option<String>,
+    /// The project team associated with the entity, if any.
+    #[serde(skip_serializing)]
+    pub project_team: Option<ProjectTeam>,
+    /// HTTP 1.1 Entity tag for the access-control entry.
+    #[serde(skip_serializing)]
+    pub etag: Option<String>,
+}
+
+impl AccessControl {
+    /// Creates an access-control entry granting `role` to `entity`, suitable
+    /// for use with [`ObjectAcl::insert`](crate::object_access_controls::ObjectAcl::insert),
+    /// [`BucketAcl::insert`](crate::bucket_access_controls::BucketAcl::insert) or
+    /// [`DefaultObjectAcl::insert`](crate::default_object_access_controls::DefaultObjectAcl::insert)
+    pub fn new(entity: impl Into<String>, role: Role) -> Self {
+        Self {
+            entity: Some(entity.into()),
+            role: Some(role),
+            ..Default::default()
+        }
+    }
+
+    /// Checks the entry is valid for an object, or the default object ACL of
+    /// a bucket, which can't grant the [`Role::Writer`] role
+    pub(crate) fn check_object_role(&self) -> Result<(), crate::Error> {
+        if self.role == Some(Role::Writer) {
+            return Err(crate::Error::InvalidObjectRole);
+        }
+
+        Ok(())
+    }
+}
+
+/// The owner of a bucket or object
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Owner {
+    /// The entity, in the form `project-owner-{projectId}` for buckets or
+    /// `user-{emailAddress}` for objects.
+    pub entity: Option<String>,
+    /// The ID for the entity.
+    pub entity_id: Option<String>,
+}
+
+/// The response from listing the entries of an ACL, ie. a bucket's ACL, an
+/// object's ACL, or a bucket's default object ACL
+pub struct ListAccessControlsResponse {
+    pub items: Vec<AccessControl>,
+}
+
+impl crate::response::ApiResponse<&[u8]> for ListAccessControlsResponse {}
+impl crate::response::ApiResponse<bytes::Bytes> for ListAccessControlsResponse {}
+
+impl<B> TryFrom<http::Response<B>> for ListAccessControlsResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = crate::Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+
+        #[derive(Deserialize)]
+        struct RawListResponse {
+            #[serde(default)]
+            items: Vec<AccessControl>,
+        }
+
+        let res: RawListResponse = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { items: res.items })
+    }
+}
+
+/// The response from getting, inserting, patching or updating a single ACL
+/// entry
+pub struct AccessControlResponse {
+    pub acl: AccessControl,
+}
+
+impl crate::response::ApiResponse<&[u8]> for AccessControlResponse {}
+impl crate::response::ApiResponse<bytes::Bytes> for AccessControlResponse {}
+
+impl<B> TryFrom<http::Response<B>> for AccessControlResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = crate::Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let acl: AccessControl = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { acl })
+    }
+}
+
+/// The response from deleting a single ACL entry
+pub struct DeleteAccessControlResponse;
+
+impl crate::response::ApiResponse<&[u8]> for DeleteAccessControlResponse {}
+impl crate::response::ApiResponse<bytes::Bytes> for DeleteAccessControlResponse {}
+
+impl<B> TryFrom<http::Response<B>> for DeleteAccessControlResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = crate::Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        if response.status() == http::StatusCode::NO_CONTENT {
+            Ok(Self)
+        } else {
+            Err(Self::Error::from(response.status()))
+        }
+    }
+}
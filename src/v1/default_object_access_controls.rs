@@ -0,0 +1,196 @@
+//! Types and APIs for interacting with GCS [Default Object Access Controls](https://cloud.google.com/storage/docs/json_api/v1/defaultObjectAccessControls)
+
+use crate::{
+    common::{
+        AccessControl, AccessControlResponse, DeleteAccessControlResponse,
+        ListAccessControlsResponse, MetagenerationConditionals, StandardQueryParameters,
+    },
+    error::Error,
+    types::BucketName,
+};
+use http::uri::Authority;
+
+/// Helper struct used to collate all of the operations available for
+/// [Default Object Access Controls](https://cloud.google.com/storage/docs/json_api/v1/defaultObjectAccessControls)
+/// Additionally, it can also be used to specify a custom authority.
+#[derive(Clone, Debug)]
+pub struct DefaultObjectAcl {
+    authority: Authority,
+}
+
+impl DefaultObjectAcl {
+    /// Supplies a custom HTTP authority, allowing a GCS host other than the
+    /// standard `storage.googleapis.com` to be used
+    pub fn with_authority(authority: Authority) -> Self {
+        Self { authority }
+    }
+}
+
+impl Default for DefaultObjectAcl {
+    /// Defaults to the standard GCS location `storage.googleapis.com`
+    fn default() -> Self {
+        Self {
+            authority: Authority::from_static("storage.googleapis.com"),
+        }
+    }
+}
+
+/// Optional parameters for all default object access control operations
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefaultObjectAccessControlOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+/// Optional parameters for [`list`](DefaultObjectAcl::list)
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListDefaultObjectAccessControlsOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// Makes the operation conditional on the metageneration of the bucket.
+    #[serde(flatten)]
+    pub conditionals: MetagenerationConditionals,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub type ListDefaultObjectAccessControlsResponse = ListAccessControlsResponse;
+pub type GetDefaultObjectAccessControlResponse = AccessControlResponse;
+pub type InsertDefaultObjectAccessControlResponse = AccessControlResponse;
+pub type PatchDefaultObjectAccessControlResponse = AccessControlResponse;
+pub type UpdateDefaultObjectAccessControlResponse = AccessControlResponse;
+pub type DeleteDefaultObjectAccessControlResponse = DeleteAccessControlResponse;
+
+impl DefaultObjectAcl {
+    fn uri<Q: serde::Serialize + Default>(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: Option<&str>,
+        optional: Option<Q>,
+    ) -> Result<String, Error> {
+        let uri = format!(
+            "https://{}/storage/v1/b/{}/defaultObjectAcl",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(bucket.as_ref(), crate::util::PATH_ENCODE_SET),
+        );
+
+        crate::util::acl_uri(uri, entity, optional)
+    }
+
+    /// Retrieves default object ACL entries on the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/defaultObjectAccessControls/list)
+    pub fn list(
+        &self,
+        bucket: &BucketName<'_>,
+        optional: Option<ListDefaultObjectAccessControlsOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let uri = self.uri(bucket, None, optional)?;
+
+        Ok(http::Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+
+    /// Returns the default object ACL entry for the specified entity on the
+    /// specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/defaultObjectAccessControls/get)
+    pub fn get(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: &str,
+        optional: Option<DefaultObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let uri = self.uri(bucket, Some(entity), optional)?;
+
+        Ok(http::Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+
+    /// Creates a new default object ACL entry on the specified bucket. Objects
+    /// created in the bucket without an explicit ACL are given the bucket's
+    /// default object ACL.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`, `storage.buckets.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/defaultObjectAccessControls/insert)
+    pub fn insert(
+        &self,
+        bucket: &BucketName<'_>,
+        acl: &AccessControl,
+        optional: Option<DefaultObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        acl.check_object_role()?;
+        let uri = self.uri(bucket, None, optional)?;
+        crate::util::acl_request("POST", uri, acl)
+    }
+
+    /// Updates a default object ACL entry on the specified bucket, only the
+    /// fields that are set in `acl` are changed.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`, `storage.buckets.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/defaultObjectAccessControls/patch)
+    pub fn patch(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: &str,
+        acl: &AccessControl,
+        optional: Option<DefaultObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        acl.check_object_role()?;
+        let uri = self.uri(bucket, Some(entity), optional)?;
+        crate::util::acl_request("PATCH", uri, acl)
+    }
+
+    /// Replaces a default object ACL entry on the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`, `storage.buckets.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/defaultObjectAccessControls/update)
+    pub fn update(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: &str,
+        acl: &AccessControl,
+        optional: Option<DefaultObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        acl.check_object_role()?;
+        let uri = self.uri(bucket, Some(entity), optional)?;
+        crate::util::acl_request("PUT", uri, acl)
+    }
+
+    /// Permanently deletes the default object ACL entry for the specified
+    /// entity on the specified bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.getIamPolicy`, `storage.buckets.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/defaultObjectAccessControls/delete)
+    pub fn delete(
+        &self,
+        bucket: &BucketName<'_>,
+        entity: &str,
+        optional: Option<DefaultObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let uri = self.uri(bucket, Some(entity), optional)?;
+
+        Ok(http::Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+}
@@ -0,0 +1,420 @@
+//! Types and APIs for interacting with GCS [HMAC keys](https://cloud.google.com/storage/docs/json_api/v1/projects/hmacKeys)
+
+use crate::{
+    common::StandardQueryParameters, error::Error, objects::Timestamp, response::ApiResponse,
+};
+use http::uri::Authority;
+
+/// Helper struct used to collate all of the operations available for
+/// [HMAC keys](https://cloud.google.com/storage/docs/json_api/v1/projects/hmacKeys)
+/// Additionally, it can also be used to specify a custom authority.
+#[derive(Clone, Debug)]
+pub struct HmacKey {
+    authority: Authority,
+}
+
+impl HmacKey {
+    /// Supplies a custom HTTP authority, allowing a GCS host other than the
+    /// standard `storage.googleapis.com` to be used
+    pub fn with_authority(authority: Authority) -> Self {
+        Self { authority }
+    }
+}
+
+impl Default for HmacKey {
+    /// Defaults to the standard GCS location `storage.googleapis.com`
+    fn default() -> Self {
+        Self {
+            authority: Authority::from_static("storage.googleapis.com"),
+        }
+    }
+}
+
+/// The state of an HMAC key
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HmacKeyState {
+    /// The key can be used to authenticate requests.
+    Active,
+    /// The key can't be used to authenticate requests, but can be reactivated.
+    /// Keys must be inactive before they can be deleted.
+    Inactive,
+    /// The key has been deleted and can no longer be used or reactivated.
+    Deleted,
+}
+
+/// The [metadata](https://cloud.google.com/storage/docs/json_api/v1/projects/hmacKeys#resource)
+/// of an HMAC key. The secret is never included, it is only available in the
+/// [`CreateHmacKeyResponse`] when the key is created.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HmacKeyMetadata {
+    /// The ID of the HMAC key, including the project ID and the access ID.
+    pub id: Option<String>,
+    /// The link to this resource.
+    pub self_link: Option<String>,
+    /// The ID of the HMAC key, used as the access key in requests.
+    pub access_id: String,
+    /// The ID of the project that owns the service account to which the key
+    /// authenticates.
+    pub project_id: Option<String>,
+    /// The email address of the key's associated service account.
+    pub service_account_email: String,
+    /// The state of the key.
+    pub state: HmacKeyState,
+    /// The creation time of the HMAC key in RFC 3339 format.
+    #[serde(default, deserialize_with = "crate::objects::timestamp_rfc3339_opt")]
+    pub time_created: Option<Timestamp>,
+    /// The last modification time of the HMAC key metadata in RFC 3339 format.
+    #[serde(default, deserialize_with = "crate::objects::timestamp_rfc3339_opt")]
+    pub updated: Option<Timestamp>,
+    /// HTTP 1.1 Entity tag for the HMAC key.
+    pub etag: Option<String>,
+}
+
+/// Optional parameters when getting, updating or deleting HMAC keys
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HmacKeyOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// The project to be billed for this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+/// Optional parameters when listing HMAC keys
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListHmacKeysOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// Maximum number of keys to return in a single response. The service
+    /// will use this parameter or 250 items, whichever is smaller.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<u32>,
+    /// A previously-returned page token representing part of the larger
+    /// set of results to view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_token: Option<&'a str>,
+    /// If present, only keys for the given service account are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_account_email: Option<&'a str>,
+    /// Whether or not to show keys in the `DELETED` state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_deleted_keys: Option<bool>,
+    /// The project to be billed for this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub struct CreateHmacKeyResponse {
+    pub metadata: HmacKeyMetadata,
+    /// The base64 encoded secret of the key. This is the **only** time the
+    /// secret is available, it cannot be retrieved again.
+    pub secret: String,
+}
+
+impl ApiResponse<&[u8]> for CreateHmacKeyResponse {}
+impl ApiResponse<bytes::Bytes> for CreateHmacKeyResponse {}
+
+impl<B> TryFrom<http::Response<B>> for CreateHmacKeyResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+
+        #[derive(Deserialize)]
+        struct RawCreateResponse {
+            metadata: HmacKeyMetadata,
+            secret: String,
+        }
+
+        let res: RawCreateResponse = serde_json::from_slice(body.as_ref())?;
+
+        Ok(Self {
+            metadata: res.metadata,
+            secret: res.secret,
+        })
+    }
+}
+
+pub struct ListHmacKeysResponse {
+    /// The list of keys
+    pub keys: Vec<HmacKeyMetadata>,
+    /// The continuation token, included only if there are more items to return.
+    /// Provide this value as the `page_token` of a subsequent request in order
+    /// to return the next page of results.
+    pub page_token: Option<String>,
+}
+
+impl ApiResponse<&[u8]> for ListHmacKeysResponse {}
+impl ApiResponse<bytes::Bytes> for ListHmacKeysResponse {}
+
+impl<B> TryFrom<http::Response<B>> for ListHmacKeysResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RawListResponse {
+            next_page_token: Option<String>,
+            // This field won't be present if the list doesn't actually
+            // return any items
+            #[serde(default)]
+            items: Vec<HmacKeyMetadata>,
+        }
+
+        let res: RawListResponse = serde_json::from_slice(body.as_ref())?;
+
+        Ok(Self {
+            keys: res.items,
+            page_token: res.next_page_token,
+        })
+    }
+}
+
+pub struct GetHmacKeyResponse {
+    pub metadata: HmacKeyMetadata,
+}
+
+impl ApiResponse<&[u8]> for GetHmacKeyResponse {}
+impl ApiResponse<bytes::Bytes> for GetHmacKeyResponse {}
+
+impl<B> TryFrom<http::Response<B>> for GetHmacKeyResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let metadata: HmacKeyMetadata = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { metadata })
+    }
+}
+
+pub struct UpdateHmacKeyResponse {
+    pub metadata: HmacKeyMetadata,
+}
+
+impl ApiResponse<&[u8]> for UpdateHmacKeyResponse {}
+impl ApiResponse<bytes::Bytes> for UpdateHmacKeyResponse {}
+
+impl<B> TryFrom<http::Response<B>> for UpdateHmacKeyResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let metadata: HmacKeyMetadata = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { metadata })
+    }
+}
+
+pub struct DeleteHmacKeyResponse;
+
+impl ApiResponse<&[u8]> for DeleteHmacKeyResponse {}
+impl ApiResponse<bytes::Bytes> for DeleteHmacKeyResponse {}
+
+impl<B> TryFrom<http::Response<B>> for DeleteHmacKeyResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        if response.status() == http::StatusCode::NO_CONTENT {
+            Ok(Self)
+        } else {
+            Err(Self::Error::from(response.status()))
+        }
+    }
+}
+
+impl HmacKey {
+    fn uri(&self, project: &str, access_id: Option<&str>) -> String {
+        let mut uri = format!(
+            "https://{}/storage/v1/projects/{}/hmacKeys",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(project.as_bytes(), crate::util::PATH_ENCODE_SET),
+        );
+
+        if let Some(access_id) = access_id {
+            uri.push('/');
+            uri.extend(percent_encoding::percent_encode(
+                access_id.as_bytes(),
+                crate::util::PATH_ENCODE_SET,
+            ));
+        }
+
+        uri
+    }
+
+    /// Creates a new HMAC key for the specified service account.
+    ///
+    /// Required IAM Permissions: `storage.hmacKeys.create`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/projects/hmacKeys/create)
+    pub fn create(
+        &self,
+        project: &str,
+        service_account_email: &str,
+        optional: Option<HmacKeyOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = self.uri(project, None);
+        uri.push_str("?serviceAccountEmail=");
+        uri.extend(percent_encoding::percent_encode(
+            service_account_email.as_bytes(),
+            crate::util::QUERY_ENCODE_SET,
+        ));
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('&');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder
+            .method("POST")
+            .header(http::header::CONTENT_LENGTH, 0u64)
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+
+    /// Retrieves a list of HMAC keys matching the criteria.
+    ///
+    /// Required IAM Permissions: `storage.hmacKeys.list`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/projects/hmacKeys/list)
+    pub fn list(
+        &self,
+        project: &str,
+        optional: Option<ListHmacKeysOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = self.uri(project, None);
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
+    }
+
+    /// Retrieves an HMAC key's metadata.
+    ///
+    /// Required IAM Permissions: `storage.hmacKeys.get`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/projects/hmacKeys/get)
+    pub fn get(
+        &self,
+        project: &str,
+        access_id: &str,
+        optional: Option<HmacKeyOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = self.uri(project, Some(access_id));
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
+    }
+
+    /// Updates the state of an HMAC key. Keys can only be made
+    /// [`Active`](HmacKeyState::Active) or [`Inactive`](HmacKeyState::Inactive),
+    /// use [`delete`](#method.delete) to delete an inactive key.
+    ///
+    /// Required IAM Permissions: `storage.hmacKeys.update`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/projects/hmacKeys/update)
+    pub fn update(
+        &self,
+        project: &str,
+        access_id: &str,
+        state: HmacKeyState,
+        optional: Option<HmacKeyOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        if state == HmacKeyState::Deleted {
+            return Err(Error::InvalidHmacKeyState);
+        }
+
+        let mut uri = self.uri(project, Some(access_id));
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        #[derive(Serialize)]
+        struct UpdateBody {
+            state: HmacKeyState,
+        }
+
+        let body = serde_json::to_vec(&UpdateBody { state })?;
+        let len = body.len();
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder
+            .method("PUT")
+            .header("content-type", "application/json")
+            .header("content-length", len)
+            .uri(uri)
+            .body(std::io::Cursor::new(body))?)
+    }
+
+    /// Deletes an HMAC key. The key must be in the
+    /// [`Inactive`](HmacKeyState::Inactive) state before it can be deleted.
+    ///
+    /// Required IAM Permissions: `storage.hmacKeys.delete`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/projects/hmacKeys/delete)
+    pub fn delete(
+        &self,
+        project: &str,
+        access_id: &str,
+        optional: Option<HmacKeyOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = self.uri(project, Some(access_id));
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder
+            .method("DELETE")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+}
@@ -0,0 +1,213 @@
+//! Types for working with [IAM policies](https://cloud.google.com/storage/docs/access-control/iam)
+//! on buckets and objects
+
+use crate::{error::Error, response::ApiResponse};
+
+/// The policy version required for policies that contain
+/// [conditional role bindings](https://cloud.google.com/storage/docs/access-control/iam#conditions).
+/// Use this as the `options_requested_policy_version` when getting a bucket's
+/// policy, otherwise conditional bindings are omitted from the response.
+pub const CONDITIONAL_POLICY_VERSION: u32 = 3;
+
+/// A bucket or object [IAM policy](https://cloud.google.com/storage/docs/json_api/v1/buckets/getIamPolicy#response)
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Policy {
+    /// The ID of the resource to which this policy belongs.
+    #[serde(skip_serializing)]
+    pub resource_id: Option<String>,
+    /// The IAM policy format version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    /// An association between a role, which comes with a set of permissions,
+    /// and members who may assume that role.
+    #[serde(default)]
+    pub bindings: Vec<Binding>,
+    /// HTTP 1.1 Entity tag for the policy. This is used for optimistic
+    /// concurrency, a policy with an etag that doesn't match the current
+    /// policy's etag will be rejected when set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+}
+
+impl Policy {
+    /// Adds the member to the unconditional binding for the role, creating
+    /// the binding if it doesn't already exist. Returns `false` if the member
+    /// was already bound to the role.
+    ///
+    /// The etag is left untouched, so that setting the modified policy will
+    /// fail if the policy was changed by someone else in the meantime.
+    pub fn add_member(&mut self, role: &str, member: &str) -> bool {
+        let Some(binding) = self
+            .bindings
+            .iter_mut()
+            .find(|binding| binding.role == role && binding.condition.is_none())
+        else {
+            self.bindings.push(Binding {
+                role: role.to_owned(),
+                members: vec![member.to_owned()],
+                condition: None,
+            });
+            return true;
+        };
+
+        if binding.members.iter().any(|m| m == member) {
+            return false;
+        }
+
+        binding.members.push(member.to_owned());
+        true
+    }
+
+    /// Removes the member from the unconditional binding for the role, removing
+    /// the binding entirely if it no longer has any members. Returns `false` if
+    /// the member was not bound to the role.
+    ///
+    /// The etag is left untouched, so that setting the modified policy will
+    /// fail if the policy was changed by someone else in the meantime.
+    pub fn remove_member(&mut self, role: &str, member: &str) -> bool {
+        let Some(index) = self
+            .bindings
+            .iter()
+            .position(|binding| binding.role == role && binding.condition.is_none())
+        else {
+            return false;
+        };
+
+        let binding = &mut self.bindings[index];
+        let len = binding.members.len();
+        binding.members.retain(|m| m != member);
+        let removed = binding.members.len() != len;
+
+        if binding.members.is_empty() {
+            self.bindings.remove(index);
+        }
+
+        removed
+    }
+}
+
+/// An association between a role and the members who may assume that role
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Binding {
+    /// The role to which members belong, eg. `roles/storage.objectViewer`.
+    pub role: String,
+    /// A collection of identifiers for members who may assume the provided
+    /// role, eg. `user:someone@example.com`, `serviceAccount:...`, `allUsers`.
+    #[serde(default)]
+    pub members: Vec<String>,
+    /// The condition that is associated with this binding, if any. Conditional
+    /// bindings require the policy to use [`CONDITIONAL_POLICY_VERSION`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<Expr>,
+}
+
+/// A [Common Expression Language](https://github.com/google/cel-spec)
+/// expression, used as the condition of a [`Binding`]
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Expr {
+    /// Textual representation of an expression in Common Expression Language syntax.
+    pub expression: String,
+    /// An optional title for the expression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// An optional description of the expression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// An optional string indicating the location of the expression for error reporting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+pub struct GetIamPolicyResponse {
+    pub policy: Policy,
+}
+
+impl ApiResponse<&[u8]> for GetIamPolicyResponse {}
+impl ApiResponse<bytes::Bytes> for GetIamPolicyResponse {}
+
+impl<B> TryFrom<http::Response<B>> for GetIamPolicyResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let policy: Policy = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { policy })
+    }
+}
+
+pub struct SetIamPolicyResponse {
+    pub policy: Policy,
+}
+
+impl ApiResponse<&[u8]> for SetIamPolicyResponse {}
+impl ApiResponse<bytes::Bytes> for SetIamPolicyResponse {}
+
+impl<B> TryFrom<http::Response<B>> for SetIamPolicyResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let policy: Policy = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { policy })
+    }
+}
+
+pub struct TestIamPermissionsResponse {
+    /// The subset of the requested permissions that the caller has
+    pub permissions: Vec<String>,
+}
+
+impl ApiResponse<&[u8]> for TestIamPermissionsResponse {}
+impl ApiResponse<bytes::Bytes> for TestIamPermissionsResponse {}
+
+impl<B> TryFrom<http::Response<B>> for TestIamPermissionsResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+
+        #[derive(Deserialize)]
+        struct RawTestResponse {
+            // This field won't be present if the caller has none of the
+            // requested permissions
+            #[serde(default)]
+            permissions: Vec<String>,
+        }
+
+        let res: RawTestResponse = serde_json::from_slice(body.as_ref())?;
+        Ok(Self {
+            permissions: res.permissions,
+        })
+    }
+}
+
+/// Appends each of the permissions to be tested as a query parameter, GCS
+/// rejects requests that don't test any permissions
+pub(crate) fn push_permissions(uri: &mut String, permissions: &[&str]) -> Result<(), Error> {
+    if permissions.is_empty() {
+        return Err(Error::MissingPermissions);
+    }
+
+    for (i, permission) in permissions.iter().enumerate() {
+        uri.push(if i == 0 { '?' } else { '&' });
+        uri.push_str("permissions=");
+        uri.extend(percent_encoding::percent_encode(
+            permission.as_bytes(),
+            crate::util::QUERY_ENCODE_SET,
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,326 @@
+//! Types and APIs for interacting with GCS [Notifications](https://cloud.google.com/storage/docs/json_api/v1/notifications)
+
+use crate::{
+    common::StandardQueryParameters, error::Error, response::ApiResponse, types::BucketName,
+};
+use http::uri::Authority;
+use std::collections::BTreeMap;
+
+mod event;
+
+pub use event::*;
+
+/// Helper struct used to collate all of the operations available for
+/// [Notifications](https://cloud.google.com/storage/docs/json_api/v1/notifications)
+/// Additionally, it can also be used to specify a custom authority.
+#[derive(Clone, Debug)]
+pub struct Notification {
+    authority: Authority,
+}
+
+impl Notification {
+    /// Supplies a custom HTTP authority, allowing a GCS host other than the
+    /// standard `storage.googleapis.com` to be used
+    pub fn with_authority(authority: Authority) -> Self {
+        Self { authority }
+    }
+}
+
+impl Default for Notification {
+    /// Defaults to the standard GCS location `storage.googleapis.com`
+    fn default() -> Self {
+        Self {
+            authority: Authority::from_static("storage.googleapis.com"),
+        }
+    }
+}
+
+/// The [event types](https://cloud.google.com/storage/docs/pubsub-notifications#events)
+/// that can trigger a notification
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventType {
+    /// Sent when a new object (or a new generation of an existing object) is
+    /// successfully created in the bucket.
+    ObjectFinalize,
+    /// Sent when the metadata of an existing object changes.
+    ObjectMetadataUpdate,
+    /// Sent when an object has been permanently deleted.
+    ObjectDelete,
+    /// Only sent when a bucket has enabled object versioning, indicates that
+    /// the live version of an object has become a noncurrent version.
+    ObjectArchive,
+}
+
+/// The format of the payload delivered with each notification
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PayloadFormat {
+    /// The payload is the JSON representation of the object's metadata, ie.
+    /// the same as would be returned by [`Object::get`](crate::objects::Object::get)
+    #[serde(rename = "JSON_API_V1")]
+    JsonApiV1,
+    /// No payload is included with the notification
+    #[serde(rename = "NONE")]
+    None,
+}
+
+/// A [notification configuration](https://cloud.google.com/storage/docs/json_api/v1/notifications#resource)
+/// that publishes change events on a bucket to a Pub/Sub topic
+#[derive(Default, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationConfig {
+    /// The ID of the notification.
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+    /// The canonical URL of this notification.
+    #[serde(skip_serializing)]
+    pub self_link: Option<String>,
+    /// The Pub/Sub topic to which this subscription publishes, formatted as
+    /// `//pubsub.googleapis.com/projects/{project-identifier}/topics/{my-topic}`.
+    /// Required when inserting a notification. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    /// If present, only send notifications about listed event types. If empty,
+    /// notifications are sent for all event types. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_types: Option<Vec<EventType>>,
+    /// An optional list of additional attributes to attach to each Pub/Sub
+    /// message published for this notification subscription. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_attributes: Option<BTreeMap<String, String>>,
+    /// The desired content of the payload. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_format: Option<PayloadFormat>,
+    /// If present, only apply this notification configuration to object names
+    /// that begin with this prefix. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_name_prefix: Option<String>,
+    /// HTTP 1.1 Entity tag for this subscription notification.
+    #[serde(skip_serializing)]
+    pub etag: Option<String>,
+}
+
+impl NotificationConfig {
+    /// Creates a notification configuration that publishes events to the
+    /// specified topic, with a [`PayloadFormat::JsonApiV1`] payload
+    pub fn new(topic: impl Into<String>) -> Self {
+        Self {
+            topic: Some(topic.into()),
+            payload_format: Some(PayloadFormat::JsonApiV1),
+            ..Default::default()
+        }
+    }
+}
+
+/// Optional parameters for all notification operations
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub struct ListNotificationsResponse {
+    pub items: Vec<NotificationConfig>,
+}
+
+impl ApiResponse<&[u8]> for ListNotificationsResponse {}
+impl ApiResponse<bytes::Bytes> for ListNotificationsResponse {}
+
+impl<B> TryFrom<http::Response<B>> for ListNotificationsResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+
+        #[derive(Deserialize)]
+        struct RawListResponse {
+            // This field won't be present if the bucket has no notifications
+            #[serde(default)]
+            items: Vec<NotificationConfig>,
+        }
+
+        let res: RawListResponse = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { items: res.items })
+    }
+}
+
+pub struct GetNotificationResponse {
+    pub notification: NotificationConfig,
+}
+
+impl ApiResponse<&[u8]> for GetNotificationResponse {}
+impl ApiResponse<bytes::Bytes> for GetNotificationResponse {}
+
+impl<B> TryFrom<http::Response<B>> for GetNotificationResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let notification: NotificationConfig = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { notification })
+    }
+}
+
+pub struct InsertNotificationResponse {
+    pub notification: NotificationConfig,
+}
+
+impl ApiResponse<&[u8]> for InsertNotificationResponse {}
+impl ApiResponse<bytes::Bytes> for InsertNotificationResponse {}
+
+impl<B> TryFrom<http::Response<B>> for InsertNotificationResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let notification: NotificationConfig = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { notification })
+    }
+}
+
+pub struct DeleteNotificationResponse;
+
+impl ApiResponse<&[u8]> for DeleteNotificationResponse {}
+impl ApiResponse<bytes::Bytes> for DeleteNotificationResponse {}
+
+impl<B> TryFrom<http::Response<B>> for DeleteNotificationResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        if response.status() == http::StatusCode::NO_CONTENT {
+            Ok(Self)
+        } else {
+            Err(Self::Error::from(response.status()))
+        }
+    }
+}
+
+impl Notification {
+    fn uri(
+        &self,
+        bucket: &BucketName<'_>,
+        notification: Option<&str>,
+        optional: Option<NotificationOptional<'_>>,
+    ) -> Result<String, Error> {
+        let mut uri = format!(
+            "https://{}/storage/v1/b/{}/notificationConfigs",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(bucket.as_ref(), crate::util::PATH_ENCODE_SET),
+        );
+
+        if let Some(notification) = notification {
+            uri.push('/');
+            uri.extend(percent_encoding::percent_encode(
+                notification.as_bytes(),
+                crate::util::PATH_ENCODE_SET,
+            ));
+        }
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        Ok(uri)
+    }
+
+    /// Retrieves a list of notification subscriptions for a given bucket.
+    ///
+    /// Required IAM Permissions: `storage.buckets.get`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/notifications/list)
+    pub fn list(
+        &self,
+        bucket: &BucketName<'_>,
+        optional: Option<NotificationOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let uri = self.uri(bucket, None, optional)?;
+
+        Ok(http::Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+
+    /// View a notification configuration.
+    ///
+    /// Required IAM Permissions: `storage.buckets.get`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/notifications/get)
+    pub fn get(
+        &self,
+        bucket: &BucketName<'_>,
+        notification: &str,
+        optional: Option<NotificationOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let uri = self.uri(bucket, Some(notification), optional)?;
+
+        Ok(http::Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+
+    /// Creates a notification subscription for a given bucket. The Cloud
+    /// Storage service account for the project must be allowed to publish to
+    /// the topic.
+    ///
+    /// Required IAM Permissions: `storage.buckets.update`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/notifications/insert)
+    pub fn insert(
+        &self,
+        bucket: &BucketName<'_>,
+        config: &NotificationConfig,
+        optional: Option<NotificationOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        let uri = self.uri(bucket, None, optional)?;
+
+        let body = serde_json::to_vec(config)?;
+        let len = body.len();
+
+        Ok(http::Request::builder()
+            .method("POST")
+            .header("content-type", "application/json")
+            .header("content-length", len)
+            .uri(uri)
+            .body(std::io::Cursor::new(body))?)
+    }
+
+    /// Permanently deletes a notification subscription.
+    ///
+    /// Required IAM Permissions: `storage.buckets.update`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/notifications/delete)
+    pub fn delete(
+        &self,
+        bucket: &BucketName<'_>,
+        notification: &str,
+        optional: Option<NotificationOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let uri = self.uri(bucket, Some(notification), optional)?;
+
+        Ok(http::Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+}
@@ -0,0 +1,136 @@
+use super::{EventType, PayloadFormat};
+use crate::{
+    error::Error,
+    objects::{Metadata, Timestamp},
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The [attributes](https://cloud.google.com/storage/docs/pubsub-notifications#attributes)
+/// attached to every Pub/Sub message published for a notification configuration
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationAttributes {
+    /// The type of event that has just occurred.
+    pub event_type: EventType,
+    /// The relative path of the notification configuration that triggered the
+    /// notification, eg. `projects/_/buckets/{bucket}/notificationConfigs/{id}`.
+    pub notification_config: String,
+    /// The format of the object payload.
+    pub payload_format: PayloadFormat,
+    /// The name of the bucket that contains the changed object.
+    pub bucket_id: String,
+    /// The name of the changed object.
+    pub object_id: String,
+    /// The generation of the changed object.
+    #[serde(deserialize_with = "crate::objects::from_str")]
+    pub object_generation: i64,
+    /// The time the event occurred.
+    #[serde(default, deserialize_with = "crate::objects::timestamp_rfc3339_opt")]
+    pub event_time: Option<Timestamp>,
+    /// For `OBJECT_ARCHIVE` and `OBJECT_DELETE` events, the generation of the
+    /// object that replaced the changed object, if it was replaced rather than
+    /// deleted outright.
+    #[serde(default, deserialize_with = "crate::objects::from_str_opt")]
+    pub overwritten_by_generation: Option<i64>,
+    /// For `OBJECT_FINALIZE` events, the generation of the object that was
+    /// replaced by the new object, if any.
+    #[serde(default, deserialize_with = "crate::objects::from_str_opt")]
+    pub overwrote_generation: Option<i64>,
+    /// Any other attributes, which includes the `custom_attributes` of the
+    /// [`NotificationConfig`](super::NotificationConfig).
+    #[serde(flatten)]
+    pub custom_attributes: BTreeMap<String, String>,
+}
+
+impl NotificationAttributes {
+    /// Parses the attributes from the key/value pairs of a Pub/Sub message
+    pub fn from_attributes<I, K, V>(attributes: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        use serde::de::value::{BorrowedStrDeserializer, MapDeserializer};
+
+        let attributes: Vec<_> = attributes
+            .into_iter()
+            .map(|(k, v)| (k.as_ref().to_owned(), v.as_ref().to_owned()))
+            .collect();
+
+        // The attribute values are deserialized the same way as the string
+        // encoded values in JSON API responses, which requires borrowing
+        let map = MapDeserializer::<_, serde_json::Error>::new(attributes.iter().map(|(k, v)| {
+            (
+                BorrowedStrDeserializer::new(k.as_str()),
+                BorrowedStrDeserializer::new(v.as_str()),
+            )
+        }));
+
+        Ok(Self::deserialize(map)?)
+    }
+}
+
+/// The details of a single change to an object
+pub struct ObjectEvent {
+    /// The attributes of the message describing the change
+    pub attributes: NotificationAttributes,
+    /// The metadata of the object, only present if the notification
+    /// configuration used [`PayloadFormat::JsonApiV1`]. For `OBJECT_DELETE`
+    /// and `OBJECT_ARCHIVE` events this is the metadata of the object as it
+    /// was at the time of the event.
+    pub metadata: Option<Metadata>,
+}
+
+/// A decoded GCS [Pub/Sub notification](https://cloud.google.com/storage/docs/pubsub-notifications)
+pub enum StorageEvent {
+    /// A new object, or a new generation of an existing object, was created.
+    ObjectFinalize(ObjectEvent),
+    /// The metadata of an existing object changed.
+    ObjectMetadataUpdate(ObjectEvent),
+    /// An object was permanently deleted.
+    ObjectDelete(ObjectEvent),
+    /// The live version of an object became a noncurrent version.
+    ObjectArchive(ObjectEvent),
+}
+
+impl StorageEvent {
+    /// Decodes a Pub/Sub message published by GCS. `data` is the message's
+    /// payload, **after** it has been base64 decoded, as is done by the Pub/Sub
+    /// client libraries but not by push subscriptions.
+    pub fn from_message<I, K, V>(attributes: I, data: &[u8]) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let attributes = NotificationAttributes::from_attributes(attributes)?;
+
+        let metadata = match attributes.payload_format {
+            PayloadFormat::JsonApiV1 => Some(serde_json::from_slice(data)?),
+            PayloadFormat::None => None,
+        };
+
+        let event = ObjectEvent {
+            attributes,
+            metadata,
+        };
+
+        Ok(match event.attributes.event_type {
+            EventType::ObjectFinalize => Self::ObjectFinalize(event),
+            EventType::ObjectMetadataUpdate => Self::ObjectMetadataUpdate(event),
+            EventType::ObjectDelete => Self::ObjectDelete(event),
+            EventType::ObjectArchive => Self::ObjectArchive(event),
+        })
+    }
+
+    /// The details of the change, regardless of the type of event
+    pub fn event(&self) -> &ObjectEvent {
+        match self {
+            Self::ObjectFinalize(event)
+            | Self::ObjectMetadataUpdate(event)
+            | Self::ObjectDelete(event)
+            | Self::ObjectArchive(event) => event,
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Types and APIs for interacting with GCS [Object Access Controls](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls)
+
+use crate::{
+    common::{
+        AccessControl, AccessControlResponse, DeleteAccessControlResponse,
+        ListAccessControlsResponse, StandardQueryParameters,
+    },
+    error::Error,
+    types::ObjectIdentifier,
+};
+use http::uri::Authority;
+
+/// Helper struct used to collate all of the operations available for
+/// [Object Access Controls](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls)
+/// Additionally, it can also be used to specify a custom authority.
+#[derive(Clone, Debug)]
+pub struct ObjectAcl {
+    authority: Authority,
+}
+
+impl ObjectAcl {
+    /// Supplies a custom HTTP authority, allowing a GCS host other than the
+    /// standard `storage.googleapis.com` to be used
+    pub fn with_authority(authority: Authority) -> Self {
+        Self { authority }
+    }
+}
+
+impl Default for ObjectAcl {
+    /// Defaults to the standard GCS location `storage.googleapis.com`
+    fn default() -> Self {
+        Self {
+            authority: Authority::from_static("storage.googleapis.com"),
+        }
+    }
+}
+
+/// An [access-control entry](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls#resource)
+/// for an object, which is the same for buckets and default object ACLs.
+pub type ObjectAccessControl = AccessControl;
+
+/// Optional parameters for all object access control operations
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectAccessControlOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// If present, selects a specific revision of this object
+    /// (as opposed to the latest version, the default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation: Option<i64>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub type ListObjectAccessControlsResponse = ListAccessControlsResponse;
+pub type GetObjectAccessControlResponse = AccessControlResponse;
+pub type InsertObjectAccessControlResponse = AccessControlResponse;
+pub type PatchObjectAccessControlResponse = AccessControlResponse;
+pub type UpdateObjectAccessControlResponse = AccessControlResponse;
+pub type DeleteObjectAccessControlResponse = DeleteAccessControlResponse;
+
+impl ObjectAcl {
+    fn uri<'a, OID>(
+        &self,
+        id: &OID,
+        entity: Option<&str>,
+        optional: Option<ObjectAccessControlOptional<'_>>,
+    ) -> Result<String, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let uri = crate::__make_obj_url!("https://{}/storage/v1/b/{}/o/{}/acl", self.authority, id);
+
+        crate::util::acl_uri(uri, entity, optional)
+    }
+
+    /// Retrieves ACL entries on the specified object.
+    ///
+    /// Required IAM Permissions: `storage.objects.getIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls/list)
+    pub fn list<'a, OID>(
+        &self,
+        id: &OID,
+        optional: Option<ObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let uri = self.uri(id, None, optional)?;
+
+        Ok(http::Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+
+    /// Returns the ACL entry for the specified entity on the specified object.
+    ///
+    /// Required IAM Permissions: `storage.objects.getIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls/get)
+    pub fn get<'a, OID>(
+        &self,
+        id: &OID,
+        entity: &str,
+        optional: Option<ObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let uri = self.uri(id, Some(entity), optional)?;
+
+        Ok(http::Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+
+    /// Creates a new ACL entry on the specified object.
+    ///
+    /// Required IAM Permissions: `storage.objects.getIamPolicy`, `storage.objects.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls/insert)
+    pub fn insert<'a, OID>(
+        &self,
+        id: &OID,
+        acl: &AccessControl,
+        optional: Option<ObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        acl.check_object_role()?;
+        let uri = self.uri(id, None, optional)?;
+        crate::util::acl_request("POST", uri, acl)
+    }
+
+    /// Updates an ACL entry on the specified object, only the fields that are
+    /// set in `acl` are changed.
+    ///
+    /// Required IAM Permissions: `storage.objects.getIamPolicy`, `storage.objects.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls/patch)
+    pub fn patch<'a, OID>(
+        &self,
+        id: &OID,
+        entity: &str,
+        acl: &AccessControl,
+        optional: Option<ObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        acl.check_object_role()?;
+        let uri = self.uri(id, Some(entity), optional)?;
+        crate::util::acl_request("PATCH", uri, acl)
+    }
+
+    /// Replaces an ACL entry on the specified object.
+    ///
+    /// Required IAM Permissions: `storage.objects.getIamPolicy`, `storage.objects.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls/update)
+    pub fn update<'a, OID>(
+        &self,
+        id: &OID,
+        entity: &str,
+        acl: &AccessControl,
+        optional: Option<ObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        acl.check_object_role()?;
+        let uri = self.uri(id, Some(entity), optional)?;
+        crate::util::acl_request("PUT", uri, acl)
+    }
+
+    /// Permanently deletes the ACL entry for the specified entity on the specified object.
+    ///
+    /// Required IAM Permissions: `storage.objects.getIamPolicy`, `storage.objects.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objectAccessControls/delete)
+    pub fn delete<'a, OID>(
+        &self,
+        id: &OID,
+        entity: &str,
+        optional: Option<ObjectAccessControlOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let uri = self.uri(id, Some(entity), optional)?;
+
+        Ok(http::Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .body(std::io::empty())?)
+    }
+}
@@ -1,6 +1,6 @@
 //! Types and APIs for interacting with GCS [Objects](https://cloud.google.com/storage/docs/json_api/v1/objects)
 
-use crate::common::StorageClass;
+use crate::common::{AccessControl, Owner, StorageClass};
 use http::uri::Authority;
 use std::collections::BTreeMap;
 
@@ -17,21 +17,35 @@ macro_rules! __make_obj_url {
     }};
 }
 
+mod compose;
+mod composite;
+mod copy;
 mod delete;
 mod download;
+mod encryption;
 mod get;
+mod iam;
 mod insert;
 mod list;
 mod patch;
+mod restore;
 mod rewrite;
+mod update;
 
+pub use compose::*;
+pub use composite::*;
+pub use copy::*;
 pub use delete::*;
 pub use download::*;
+pub use encryption::*;
 pub use get::*;
+pub use iam::*;
 pub use insert::*;
 pub use list::*;
 pub use patch::*;
+pub use restore::*;
 pub use rewrite::*;
+pub use update::*;
 
 pub type Timestamp = time::OffsetDateTime;
 
@@ -109,6 +123,20 @@ pub struct Metadata {
     /// When the object is initially created, it will be set to timeCreated.
     #[serde(default, skip_serializing, deserialize_with = "timestamp_rfc3339_opt")]
     pub time_storage_class_updated: Option<Timestamp>,
+    /// A user-specified timestamp for the object in RFC 3339 format. Once set
+    /// on an object, it can only be changed to a later time. **writable**
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "to_timestamp_rfc3339_opt",
+        deserialize_with = "timestamp_rfc3339_opt"
+    )]
+    pub custom_time: Option<Timestamp>,
+    /// The time at which the object became noncurrent in RFC 3339 format.
+    /// Will be returned if and only if this version of the object has been
+    /// deleted.
+    #[serde(default, skip_serializing, deserialize_with = "timestamp_rfc3339_opt")]
+    pub time_deleted: Option<Timestamp>,
     /// `Content-Length` of the data in bytes.
     #[serde(default, skip_serializing, deserialize_with = "from_str_opt")]
     pub size: Option<u64>,
@@ -122,6 +150,11 @@ pub struct Metadata {
     /// `Content-Language` of the object data. **writable**
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_language: Option<String>,
+    /// `Cache-Control` directive for the object data. If omitted, and the
+    /// object is accessible to all anonymous users, the default will be
+    /// `public, max-age=3600`. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<String>,
     /// `CRC32c` checksum, as described in RFC 4960, Appendix B; encoded
     /// using base64 in big-endian byte order. For more information about
     /// using the `CRC32c` checksum, see `Hashes and ETags: Best Practices`.
@@ -133,11 +166,136 @@ pub struct Metadata {
     /// User-provided metadata, in key/value pairs. **writable**
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<BTreeMap<String, String>>,
+    /// Resource name of the Cloud KMS key used to encrypt the object, if it
+    /// is encrypted with a customer-managed encryption key. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kms_key_name: Option<String>,
+    /// Metadata of the customer-supplied encryption key, if the object is
+    /// encrypted with one.
+    #[serde(skip_serializing)]
+    pub customer_encryption: Option<CustomerEncryption>,
+    /// Access controls on the object, only returned when using the `full`
+    /// projection. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acl: Option<Vec<AccessControl>>,
+    /// The owner of the object. This will always be the uploader of the object.
+    #[serde(skip_serializing)]
+    pub owner: Option<Owner>,
+    /// Number of underlying components that make up this object. Components
+    /// are accumulated by compose operations.
+    #[serde(skip_serializing)]
+    pub component_count: Option<u32>,
+    /// Whether or not the object is subject to a temporary hold, which
+    /// prevents it from being deleted or replaced. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary_hold: Option<bool>,
+    /// Whether or not the object is subject to an event-based hold, which
+    /// prevents it from being deleted or replaced, and resets the object's
+    /// time in the bucket for the purposes of the bucket's retention policy
+    /// when released. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_based_hold: Option<bool>,
+    /// The earliest time that the object can be deleted, based on the
+    /// bucket's retention policy, in RFC 3339 format.
+    #[serde(default, skip_serializing, deserialize_with = "timestamp_rfc3339_opt")]
+    pub retention_expiration_time: Option<Timestamp>,
+    /// The object's own retention configuration, which prevents it from
+    /// being deleted or replaced until the retain until time. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<ObjectRetention>,
+    /// The time at which the object became soft-deleted in RFC 3339 format.
+    /// Only returned for soft-deleted objects.
+    #[serde(default, skip_serializing, deserialize_with = "timestamp_rfc3339_opt")]
+    pub soft_delete_time: Option<Timestamp>,
+    /// The time at which the soft-deleted object will be permanently deleted
+    /// in RFC 3339 format. Only returned for soft-deleted objects.
+    #[serde(default, skip_serializing, deserialize_with = "timestamp_rfc3339_opt")]
+    pub hard_delete_time: Option<Timestamp>,
+    /// The time at which the object was finalized, ie. when its upload
+    /// completed, in RFC 3339 format.
+    #[serde(default, skip_serializing, deserialize_with = "timestamp_rfc3339_opt")]
+    pub time_finalized: Option<Timestamp>,
+    /// User-defined or system-defined contexts attached to the object.
+    /// **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contexts: Option<ObjectContexts>,
+}
+
+/// The [retention mode](https://cloud.google.com/storage/docs/object-lock)
+/// of an object
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RetentionMode {
+    /// The retention configuration can be changed or removed
+    Unlocked,
+    /// The retention configuration can only be extended
+    Locked,
+}
+
+/// The retention configuration of an individual object
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectRetention {
+    /// The retention mode of the object. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<RetentionMode>,
+    /// The time in RFC 3339 format until which the object is retained and
+    /// can't be deleted or replaced. **writable**
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "to_timestamp_rfc3339_opt",
+        deserialize_with = "timestamp_rfc3339_opt"
+    )]
+    pub retain_until_time: Option<Timestamp>,
+}
+
+/// Contexts attached to an object, which can be used to filter objects when
+/// listing them
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectContexts {
+    /// User-defined contexts, in key/value pairs. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<BTreeMap<String, ObjectCustomContext>>,
+}
+
+/// The value of a single user-defined object context
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectCustomContext {
+    /// The value of the context. **writable**
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// The time the context was created in RFC 3339 format.
+    #[serde(default, skip_serializing, deserialize_with = "timestamp_rfc3339_opt")]
+    pub create_time: Option<Timestamp>,
+    /// The time the context was last updated in RFC 3339 format.
+    #[serde(default, skip_serializing, deserialize_with = "timestamp_rfc3339_opt")]
+    pub update_time: Option<Timestamp>,
+}
+
+/// Metadata of the [`EncryptionKey`] used to encrypt an object
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerEncryption {
+    /// The encryption algorithm, always `AES256`
+    pub encryption_algorithm: String,
+    /// The base64 encoded SHA-256 hash of the encryption key
+    pub key_sha256: String,
+}
+
+impl From<&Metadata> for crate::hashing::ObjectHashes {
+    fn from(md: &Metadata) -> Self {
+        Self {
+            crc32c: md.crc32c.clone(),
+            md5_hash: md.md5_hash.clone(),
+        }
+    }
 }
 
 use serde::de::Deserialize;
 
-fn from_str_opt<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+pub(crate) fn from_str_opt<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
 where
     T: std::str::FromStr,
     T::Err: std::fmt::Display,
@@ -147,7 +305,7 @@ where
     T::from_str(s).map_err(serde::de::Error::custom).map(Some)
 }
 
-fn from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+pub(crate) fn from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
 where
     T: std::str::FromStr,
     T::Err: std::fmt::Display,
@@ -157,7 +315,7 @@ where
     T::from_str(s).map_err(serde::de::Error::custom)
 }
 
-fn timestamp_rfc3339_opt<'de, D>(deserializer: D) -> Result<Option<Timestamp>, D::Error>
+pub(crate) fn timestamp_rfc3339_opt<'de, D>(deserializer: D) -> Result<Option<Timestamp>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
@@ -166,3 +324,30 @@ where
         .map_err(serde::de::Error::custom)
         .map(Some)
 }
+
+pub(crate) fn to_str_opt<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: std::fmt::Display,
+    S: serde::Serializer,
+{
+    match value {
+        Some(v) => serializer.collect_str(v),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub(crate) fn to_timestamp_rfc3339_opt<S>(
+    ts: &Option<Timestamp>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match ts {
+        Some(ts) => serializer.collect_str(
+            &ts.format(&time::format_description::well_known::Rfc3339)
+                .map_err(serde::ser::Error::custom)?,
+        ),
+        None => serializer.serialize_none(),
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    common::{PredefinedAcl, StandardQueryParameters},
+    error::Error,
+    response::ApiResponse,
+    types::ObjectIdentifier,
+};
+
+/// The maximum number of source objects that can be composed in a single
+/// request
+pub const MAX_COMPOSE_SOURCES: usize = 32;
+
+/// Optional parameters when composing an object.
+/// See [here](https://cloud.google.com/storage/docs/json_api/v1/objects/compose#parameters)
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposeObjectOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// Apply a predefined set of access controls to the destination object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_predefined_acl: Option<PredefinedAcl>,
+    /// Makes the operation conditional on whether the destination object's
+    /// current generation matches the given value. Setting to 0 makes the
+    /// operation succeed only if there are no live versions of the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_generation_match: Option<i64>,
+    /// Makes the operation conditional on whether the destination object's
+    /// current metageneration matches the given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_metageneration_match: Option<i64>,
+    /// Resource name of the Cloud KMS key that will be used to encrypt
+    /// the composed object. Overrides the object metadata's `kms_key_name`
+    /// value, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kms_key_name: Option<&'a str>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+/// A source object that is concatenated, in order, into the destination
+/// object of a compose request. All source objects must reside in the same
+/// bucket as the destination object.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposeSource {
+    /// The source object's name.
+    pub name: String,
+    /// The generation of this object to use as the source, defaults to
+    /// the live version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation: Option<i64>,
+    /// Conditions that must be met for this operation to execute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_preconditions: Option<ComposePreconditions>,
+}
+
+impl ComposeSource {
+    /// Creates a source referring to the live version of the named object
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Conditions on a [`ComposeSource`] that must be met for the compose to
+/// proceed
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposePreconditions {
+    /// Only perform the composition if the generation of the source object
+    /// that would be used matches this value. If this value and a generation
+    /// are both specified, they must be the same value or the call will fail.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_generation_match: Option<i64>,
+}
+
+/// The [request body](https://cloud.google.com/storage/docs/json_api/v1/objects/compose#request-body)
+/// of a compose request.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposeRequest<'a> {
+    /// The list of source objects that will be concatenated into a single object.
+    pub source_objects: &'a [ComposeSource],
+    /// Properties of the resulting object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<&'a super::Metadata>,
+}
+
+/// The response from a [`compose`](#method.compose) request is the object
+/// [metadata](https://cloud.google.com/storage/docs/json_api/v1/objects#resource)
+/// for the newly composed object.
+pub struct ComposeObjectResponse {
+    pub metadata: super::Metadata,
+}
+
+impl ApiResponse<&[u8]> for ComposeObjectResponse {}
+impl ApiResponse<bytes::Bytes> for ComposeObjectResponse {}
+
+impl<B> TryFrom<http::Response<B>> for ComposeObjectResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let metadata: super::Metadata = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { metadata })
+    }
+}
+
+impl super::Object {
+    /// Concatenates a list of existing objects into a new object in the same bucket.
+    ///
+    /// * Maximum number of source objects: `32`
+    ///
+    /// Required IAM Permissions: `storage.objects.create`, `storage.objects.get`,
+    /// `storage.objects.delete`
+    ///
+    /// Note: `storage.objects.delete` is only needed if an object with the same
+    /// name as the destination already exists.
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/compose)
+    pub fn compose<'a, OID>(
+        &self,
+        destination: &OID,
+        sources: &[ComposeSource],
+        metadata: Option<&super::Metadata>,
+        optional: Option<ComposeObjectOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        if sources.is_empty() || sources.len() > MAX_COMPOSE_SOURCES {
+            return Err(Error::InvalidSourceCount {
+                len: sources.len(),
+                min: 1,
+                max: MAX_COMPOSE_SOURCES,
+            });
+        }
+
+        let mut uri = crate::__make_obj_url!(
+            "https://{}/storage/v1/b/{}/o/{}/compose",
+            self.authority,
+            destination
+        );
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        let body = serde_json::to_vec(&ComposeRequest {
+            source_objects: sources,
+            destination: metadata,
+        })?;
+        let len = body.len();
+        let body = std::io::Cursor::new(body);
+
+        Ok(req_builder
+            .method("POST")
+            .header("content-type", "application/json")
+            .header("content-length", len)
+            .uri(uri)
+            .body(body)?)
+    }
+}
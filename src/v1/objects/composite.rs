@@ -0,0 +1,366 @@
+use super::{
+    ComposeObjectOptional, ComposeSource, DeleteObjectOptional, InsertObjectOptional,
+    MAX_COMPOSE_SOURCES, Metadata, Object,
+};
+use crate::{
+    common::StandardQueryParameters,
+    error::Error,
+    hashing::{Crc32c, ObjectHashes, decode_crc32c, encode_crc32c},
+    types::{BucketName, ObjectIdentifier, ObjectName},
+};
+
+/// A part of the source of a [`ParallelCompositeUpload`], uploaded as a
+/// temporary component object
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompositePart {
+    /// The name of the temporary component object
+    pub name: String,
+    /// The offset of the part in the source
+    pub offset: u64,
+    /// The length of the part
+    pub length: u64,
+}
+
+/// A single compose request in a [`ParallelCompositeUpload`], concatenating
+/// component objects into either an intermediate object, or the final
+/// destination object
+#[derive(Clone, Debug)]
+pub struct CompositeStep {
+    /// The name of the object being composed
+    pub destination: String,
+    /// The objects being concatenated, in order
+    pub sources: Vec<ComposeSource>,
+}
+
+/// The compose requests of a single round of a [`ParallelCompositeUpload`],
+/// which can all be sent in parallel
+pub type ComposeRound = Vec<http::Request<std::io::Cursor<Vec<u8>>>>;
+
+/// Optional parameters for [`ParallelCompositeUpload::cleanup_requests`],
+/// which are sent with the request to delete each temporary object
+#[derive(Default, Clone)]
+pub struct CleanupCompositeOptional<'a> {
+    pub standard_params: StandardQueryParameters<'a>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    pub user_project: Option<&'a str>,
+}
+
+/// A sans-io planner for [parallel composite uploads](https://cloud.google.com/storage/docs/parallel-composite-uploads),
+/// where a large source is split into parts that are uploaded in parallel as
+/// temporary component objects, which are then composed into the destination
+/// object and deleted.
+///
+/// 1. Upload every part with [`ParallelCompositeUpload::insert_part`] or
+///    [`ParallelCompositeUpload::resumable_part_init`], in any order, and
+///    pass the metadata of each uploaded part to
+///    [`ParallelCompositeUpload::on_part_uploaded`]
+/// 1. Send the compose requests from [`ParallelCompositeUpload::compose_requests`],
+///    waiting for every request in a round to complete before starting the next
+/// 1. Check the final object with [`ParallelCompositeUpload::verify`]
+/// 1. Send the requests from [`ParallelCompositeUpload::cleanup_requests`],
+///    regardless of whether the upload succeeded
+pub struct ParallelCompositeUpload {
+    object: Object,
+    bucket: String,
+    parts: Vec<CompositePart>,
+    crcs: Vec<Option<u32>>,
+    generations: Vec<Option<i64>>,
+    rounds: Vec<Vec<CompositeStep>>,
+}
+
+impl ParallelCompositeUpload {
+    /// The parts the source is split into
+    pub fn parts(&self) -> &[CompositePart] {
+        &self.parts
+    }
+
+    /// The compose steps needed to create the destination object, every
+    /// step in a round can be performed in parallel, but each round must
+    /// complete before the next one starts. The last round always consists
+    /// of a single step that composes the destination object.
+    pub fn rounds(&self) -> &[Vec<CompositeStep>] {
+        &self.rounds
+    }
+
+    fn part_id(&self, index: usize) -> (BucketName<'_>, ObjectName<'_>) {
+        (
+            BucketName::non_validated(&self.bucket),
+            ObjectName::non_validated(&self.parts[index].name),
+        )
+    }
+
+    /// Creates the request to upload the part at `index`, where `content`
+    /// is the bytes of the source described by the part.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn insert_part<B>(
+        &self,
+        index: usize,
+        content: B,
+        optional: Option<InsertObjectOptional<'_>>,
+    ) -> Result<http::Request<B>, Error> {
+        let (bucket, name) = self.part_id(index);
+
+        self.object.insert_simple(
+            &(&bucket, &name),
+            content,
+            self.parts[index].length,
+            optional,
+        )
+    }
+
+    /// Creates the request to initiate a resumable upload of the part at
+    /// `index`, for parts that are too large to upload in a single request.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn resumable_part_init(
+        &self,
+        index: usize,
+        optional: Option<InsertObjectOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        let part = &self.parts[index];
+
+        self.object.resumable_insert_init_with_metadata(
+            &BucketName::non_validated(&self.bucket),
+            &Metadata {
+                name: Some(part.name.clone()),
+                ..Default::default()
+            },
+            Some(part.length),
+            None,
+            optional,
+        )
+    }
+
+    /// Records the metadata of an uploaded part, so that its `CRC32c` checksum
+    /// can be used to verify the composed object, and its generation is the
+    /// one that is composed.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn on_part_uploaded(&mut self, index: usize, metadata: &Metadata) -> Result<(), Error> {
+        let part = &self.parts[index];
+
+        if let Some(found) = metadata.size
+            && found != part.length
+        {
+            return Err(Error::PartSizeMismatch {
+                expected: part.length,
+                found,
+            });
+        }
+
+        self.crcs[index] = metadata.crc32c.as_deref().and_then(decode_crc32c);
+        self.generations[index] = metadata.generation;
+        Ok(())
+    }
+
+    /// Creates the compose requests for every round, the final one creating
+    /// the destination object with the specified `metadata` and `optional`
+    /// parameters. The standard parameters and billing project are also sent
+    /// with the intermediate compose requests. The parts are
+    /// pinned to the generations passed to [`ParallelCompositeUpload::on_part_uploaded`],
+    /// so that a part that was overwritten in the meantime fails the compose.
+    pub fn compose_requests(
+        &self,
+        metadata: Option<&Metadata>,
+        optional: Option<ComposeObjectOptional<'_>>,
+    ) -> Result<Vec<ComposeRound>, Error> {
+        let last_round = self.rounds.len() - 1;
+        let optional = optional.unwrap_or_default();
+        let standard_params = optional.standard_params.clone();
+        let user_project = optional.user_project;
+        let mut optional = Some(optional);
+
+        // The sources of the first round are always the parts, in order
+        let mut generations = self.generations.iter();
+
+        self.rounds
+            .iter()
+            .enumerate()
+            .map(|(round, steps)| {
+                steps
+                    .iter()
+                    .map(|step| {
+                        let id = (
+                            &BucketName::non_validated(&self.bucket),
+                            &ObjectName::non_validated(&step.destination),
+                        );
+
+                        let mut sources = step.sources.clone();
+                        if round == 0 {
+                            for (source, generation) in sources.iter_mut().zip(&mut generations) {
+                                source.generation = *generation;
+                            }
+                        }
+
+                        // Only the destination object gets the user's metadata and options
+                        if round == last_round {
+                            self.object
+                                .compose(&id, &sources, metadata, optional.take())
+                        } else {
+                            self.object.compose(
+                                &id,
+                                &sources,
+                                None,
+                                Some(ComposeObjectOptional {
+                                    standard_params: standard_params.clone(),
+                                    user_project,
+                                    ..Default::default()
+                                }),
+                            )
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The `CRC32c` checksum the destination object should have, known once
+    /// the metadata of every part has been passed to
+    /// [`ParallelCompositeUpload::on_part_uploaded`]
+    pub fn expected_crc32c(&self) -> Option<String> {
+        let mut crc = Crc32c::checksum(&[]);
+        for (part, part_crc) in self.parts.iter().zip(&self.crcs) {
+            crc = Crc32c::combine(crc, (*part_crc)?, part.length);
+        }
+
+        Some(encode_crc32c(crc))
+    }
+
+    /// Verifies the `CRC32c` checksum of the composed destination object
+    /// matches the combined checksums of the parts
+    pub fn verify(&self, metadata: &Metadata) -> Result<(), Error> {
+        let expected = self.expected_crc32c().ok_or(Error::MissingHash)?;
+        let calculated = metadata.crc32c.clone().ok_or(Error::MissingHash)?;
+
+        if expected != calculated {
+            return Err(Error::HashMismatch {
+                algorithm: "crc32c",
+                expected,
+                calculated,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Creates the requests to delete the temporary component objects, both
+    /// the uploaded parts and any intermediate composed objects
+    pub fn cleanup_requests(
+        &self,
+        optional: Option<CleanupCompositeOptional<'_>>,
+    ) -> Result<Vec<http::Request<std::io::Empty>>, Error> {
+        let last_round = self.rounds.len() - 1;
+        let optional = optional.unwrap_or_default();
+
+        self.parts
+            .iter()
+            .map(|part| part.name.as_str())
+            .chain(
+                self.rounds[..last_round]
+                    .iter()
+                    .flatten()
+                    .map(|step| step.destination.as_str()),
+            )
+            .map(|name| {
+                self.object.delete(
+                    &(
+                        &BucketName::non_validated(&self.bucket),
+                        &ObjectName::non_validated(name),
+                    ),
+                    Some(DeleteObjectOptional {
+                        standard_params: optional.standard_params.clone(),
+                        user_project: optional.user_project,
+                        ..Default::default()
+                    }),
+                )
+            })
+            .collect()
+    }
+
+    /// Hashes of the destination object, for use with eg. [`crate::hashing::HashVerifier`]
+    pub fn expected_hashes(&self) -> ObjectHashes {
+        ObjectHashes {
+            crc32c: self.expected_crc32c(),
+            md5_hash: None,
+        }
+    }
+}
+
+impl Object {
+    /// Plans a [`ParallelCompositeUpload`] of a source of `length` bytes into
+    /// `destination`, split into at most `part_count` parts. The temporary
+    /// component objects are named by appending to `temp_prefix`, which should
+    /// be unique to this upload, eg. `tmp/upload-<uuid>/`.
+    ///
+    /// Note that composite objects don't have an MD5 hash, and that the
+    /// temporary objects incur early deletion charges in storage classes with
+    /// a minimum storage duration.
+    pub fn parallel_composite_upload<'a, OID>(
+        &self,
+        destination: &OID,
+        length: u64,
+        part_count: usize,
+        temp_prefix: &str,
+    ) -> Result<ParallelCompositeUpload, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        if part_count == 0 {
+            return Err(Error::InvalidPartCount);
+        }
+
+        // Don't create empty parts, unless the source is empty
+        let part_count = (part_count as u64).min(length.max(1));
+        let part_size = length.div_ceil(part_count).max(1);
+
+        let parts: Vec<_> = (0..part_count)
+            .map(|i| {
+                let offset = (i * part_size).min(length);
+                CompositePart {
+                    name: format!("{temp_prefix}{i}"),
+                    offset,
+                    length: (length - offset).min(part_size),
+                }
+            })
+            .filter(|part| part.length > 0 || length == 0)
+            .collect();
+
+        for part in &parts {
+            ObjectName::try_from(part.name.as_str())?;
+        }
+
+        // Compose in rounds of at most 32 sources until a single round can
+        // create the destination object
+        let mut rounds = Vec::new();
+        let mut sources: Vec<_> = parts.iter().map(|part| part.name.clone()).collect();
+        while sources.len() > MAX_COMPOSE_SOURCES {
+            let round = rounds.len();
+            let steps: Vec<_> = sources
+                .chunks(MAX_COMPOSE_SOURCES)
+                .enumerate()
+                .map(|(i, chunk)| CompositeStep {
+                    destination: format!("{temp_prefix}compose-{round}-{i}"),
+                    sources: chunk.iter().map(ComposeSource::new).collect(),
+                })
+                .collect();
+
+            sources = steps.iter().map(|step| step.destination.clone()).collect();
+            rounds.push(steps);
+        }
+
+        rounds.push(vec![CompositeStep {
+            destination: destination.object().to_string(),
+            sources: sources.iter().map(ComposeSource::new).collect(),
+        }]);
+
+        Ok(ParallelCompositeUpload {
+            object: self.clone(),
+            bucket: destination.bucket().to_string(),
+            crcs: vec![None; parts.len()],
+            generations: vec![None; parts.len()],
+            parts,
+            rounds,
+        })
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    common::{Conditionals, PredefinedAcl, Projection, StandardQueryParameters},
+    error::Error,
+    response::ApiResponse,
+    types::ObjectIdentifier,
+};
+
+/// Optional parameters when copying an object.
+/// See [here](https://cloud.google.com/storage/docs/json_api/v1/objects/copy#parameters)
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyObjectOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// Resource name of the Cloud KMS key that will be used to encrypt the
+    /// destination object. The Cloud KMS key must be located in same location
+    /// as the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_kms_key_name: Option<&'a str>,
+    /// Apply a predefined set of access controls to the destination object.
+    ///
+    /// If iamConfiguration.uniformBucketLevelAccess.enabled is set to true,
+    /// requests that include this parameter fail with a 400 Bad Request response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_predefined_acl: Option<PredefinedAcl>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub destination_conditionals: Option<Conditionals>,
+    /// Makes the operation conditional on whether the source object's
+    /// generation matches the given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_source_generation_match: Option<i64>,
+    /// Makes the operation conditional on whether the source object's
+    /// generation does not match the given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_source_generation_not_match: Option<i64>,
+    /// Makes the operation conditional on whether the source object's current
+    /// metageneration matches the given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_source_metageneration_match: Option<i64>,
+    /// Makes the operation conditional on whether the source object's current
+    /// metageneration does not match the given value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub if_source_metageneration_not_match: Option<i64>,
+    /// Set of properties to return. Defaults to `noAcl`, unless the object
+    /// resource specifies the acl property, when it defaults to full.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Projection>,
+    /// If present, selects a specific revision of the source object (as opposed
+    /// to the latest version, the default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_generation: Option<i64>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+/// The response from a [`copy`](#method.copy) request is the object
+/// [metadata](https://cloud.google.com/storage/docs/json_api/v1/objects#resource)
+/// for the destination object.
+pub struct CopyObjectResponse {
+    pub metadata: super::Metadata,
+}
+
+impl ApiResponse<&[u8]> for CopyObjectResponse {}
+impl ApiResponse<bytes::Bytes> for CopyObjectResponse {}
+
+impl<B> TryFrom<http::Response<B>> for CopyObjectResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let metadata: super::Metadata = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { metadata })
+    }
+}
+
+impl super::Object {
+    /// Copies a source object to a destination object. Optionally overrides metadata.
+    ///
+    /// Unlike [`rewrite`](#method.rewrite), the copy is done in a single request,
+    /// which can fail for large objects when the source and destination differ
+    /// in location or storage class.
+    ///
+    /// Required IAM Permissions:
+    /// * `storage.objects.create` (for the destination bucket)
+    /// * `storage.objects.delete` (for the destination bucket)
+    /// * `storage.objects.get` (for the source bucket)
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/copy)
+    pub fn copy<'a, OID>(
+        &self,
+        source: &OID,
+        destination: &OID,
+        metadata: Option<&super::Metadata>,
+        optional: Option<CopyObjectOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let mut uri = format!(
+            "https://{}/storage/v1/b/{}/o/{}/copyTo/b/{}/o/{}",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(
+                source.bucket().as_ref(),
+                crate::util::PATH_ENCODE_SET
+            ),
+            percent_encoding::percent_encode(
+                source.object().as_ref(),
+                crate::util::PATH_ENCODE_SET
+            ),
+            percent_encoding::percent_encode(
+                destination.bucket().as_ref(),
+                crate::util::PATH_ENCODE_SET
+            ),
+            percent_encoding::percent_encode(
+                destination.object().as_ref(),
+                crate::util::PATH_ENCODE_SET
+            )
+        );
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let mut req_builder = http::Request::builder();
+
+        let body = match metadata {
+            Some(metadata) => {
+                let md = serde_json::to_vec(&metadata)?;
+                let len = md.len();
+
+                req_builder = req_builder
+                    .header("content-type", "application/json")
+                    .header("content-length", len);
+
+                std::io::Cursor::new(md)
+            }
+            None => std::io::Cursor::new(Vec::new()),
+        };
+
+        Ok(req_builder.method("POST").uri(uri).body(body)?)
+    }
+}
@@ -1,11 +1,19 @@
+use super::EncryptionKey;
 use crate::{
     common::{Conditionals, Projection, StandardQueryParameters},
     error::Error,
+    hashing::{HashVerifier, ObjectHashes, VerifyingReader},
     response::ApiResponse,
     types::ObjectIdentifier,
 };
 use std::io;
 
+mod resumable;
+mod sliced;
+
+pub use resumable::*;
+pub use sliced::*;
+
 #[derive(Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DownloadObjectOptional<'a> {
@@ -24,16 +32,227 @@ pub struct DownloadObjectOptional<'a> {
     /// The project to be billed for this request. Required for Requester Pays buckets.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_project: Option<&'a str>,
+    /// If present, only the specified range of bytes of the object is
+    /// downloaded, sent as the `Range` header rather than a query parameter.
+    #[serde(skip)]
+    pub range: Option<ByteRange>,
+    /// The customer-supplied key the object is encrypted with, sent as the
+    /// `x-goog-encryption-*` headers.
+    #[serde(skip)]
+    pub encryption_key: Option<&'a EncryptionKey>,
+}
+
+/// A range of bytes to download, as specified by the
+/// [`Range`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Range) header
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ByteRange {
+    /// `bytes={start}-{end}`, where both offsets are inclusive
+    Bounded { start: u64, end: u64 },
+    /// `bytes={start}-`, from `start` until the end of the object
+    From(u64),
+    /// `bytes=-{len}`, the last `len` bytes of the object
+    Suffix(u64),
+}
+
+impl ByteRange {
+    fn to_header(self) -> Result<http::HeaderValue, Error> {
+        let value = match self {
+            Self::Bounded { start, end } => {
+                if start > end {
+                    return Err(Error::InvalidRange);
+                }
+
+                format!("bytes={start}-{end}")
+            }
+            Self::From(start) => format!("bytes={start}-"),
+            Self::Suffix(len) => {
+                if len == 0 {
+                    return Err(Error::InvalidRange);
+                }
+
+                format!("bytes=-{len}")
+            }
+        };
+
+        Ok(http::HeaderValue::try_from(value)?)
+    }
+}
+
+/// The range of bytes contained in a `206 Partial Content` response, parsed
+/// from the [`Content-Range`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Range)
+/// header
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ContentRange {
+    /// The offset of the first byte in the response, inclusive
+    pub start: u64,
+    /// The offset of the last byte in the response, inclusive
+    pub end: u64,
+    /// The total size of the object, if known
+    pub total: Option<u64>,
+}
+
+impl ContentRange {
+    /// The number of bytes in the range
+    pub fn size(&self) -> u64 {
+        self.end - self.start + 1
+    }
+}
+
+impl TryFrom<&http::HeaderValue> for ContentRange {
+    type Error = Error;
+
+    fn try_from(value: &http::HeaderValue) -> Result<Self, Self::Error> {
+        let opaque = || Error::OpaqueHeaderValue(value.clone());
+
+        let range = value
+            .to_str()
+            .ok()
+            .and_then(|s| s.strip_prefix("bytes "))
+            .ok_or_else(opaque)?;
+
+        let (span, total) = range.split_once('/').ok_or_else(opaque)?;
+        let (start, end) = span.split_once('-').ok_or_else(opaque)?;
+
+        let start = start.trim().parse::<u64>().map_err(|_err| opaque())?;
+        let end = end.trim().parse::<u64>().map_err(|_err| opaque())?;
+        let total = match total.trim() {
+            "*" => None,
+            total => Some(total.parse::<u64>().map_err(|_err| opaque())?),
+        };
+
+        if start > end || total.is_some_and(|total| end >= total) {
+            return Err(opaque());
+        }
+
+        Ok(Self { start, end, total })
+    }
+}
+
+/// The object information GCS returns in the headers of a download response
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DownloadHeaders {
+    /// The generation of the object that was downloaded, from `x-goog-generation`
+    pub generation: Option<i64>,
+    /// The metageneration of the object that was downloaded, from
+    /// `x-goog-metageneration`
+    pub metageneration: Option<i64>,
+    /// The `Content-Encoding` the object is stored with, which can differ
+    /// from the encoding of the response if GCS performed decompressive
+    /// transcoding, from `x-goog-stored-content-encoding`
+    pub stored_content_encoding: Option<String>,
+    /// The length of the object as stored, which can differ from the length
+    /// of the response if GCS performed decompressive transcoding, from
+    /// `x-goog-stored-content-length`
+    pub stored_content_length: Option<u64>,
+    /// The `CRC32c` checksum of the stored object, encoded using base64 in
+    /// big-endian byte order, from `x-goog-hash`
+    pub crc32c: Option<String>,
+    /// The MD5 hash of the stored object, encoded using base64, from
+    /// `x-goog-hash`. Composite objects don't have an MD5 hash.
+    pub md5_hash: Option<String>,
+    /// HTTP 1.1 Entity tag for the object.
+    pub etag: Option<String>,
+    /// Content-Type of the response.
+    pub content_type: Option<String>,
+}
+
+impl DownloadHeaders {
+    /// The value of a header, or `None` if it isn't present or isn't valid
+    /// UTF-8
+    fn string(headers: &http::HeaderMap, name: &str) -> Option<String> {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from)
+    }
+
+    /// The value of a numeric header, or `None` if it isn't present or can't
+    /// be parsed
+    fn number<T: std::str::FromStr>(headers: &http::HeaderMap, name: &str) -> Option<T> {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|s| s.parse().ok())
+    }
+}
+
+impl DownloadHeaders {
+    /// Wraps the body of a download response so that its `CRC32c` checksum is
+    /// verified as it is read, for when the object is too large to buffer
+    /// as a [`DownloadObjectResponse`]
+    pub fn verifying_reader<R>(&self, body: R) -> VerifyingReader<R> {
+        VerifyingReader::new(body, HashVerifier::new(ObjectHashes::from(self)))
+    }
+}
+
+/// The headers are purely informational, so any that are malformed are
+/// ignored rather than failing the download
+impl From<&http::HeaderMap> for DownloadHeaders {
+    fn from(headers: &http::HeaderMap) -> Self {
+        let mut crc32c = None;
+        let mut md5_hash = None;
+
+        // GCS can send the hashes either as separate headers, or as a single
+        // comma separated one, eg `crc32c=n03x6A==,md5=Ojk9c3dhfxgoKVVHYwFbHQ==`
+        for value in headers.get_all("x-goog-hash") {
+            let Ok(hashes) = value.to_str() else {
+                continue;
+            };
+
+            for hash in hashes.split(',') {
+                match hash.trim().split_once('=') {
+                    Some(("crc32c", crc)) => crc32c = Some(crc.to_owned()),
+                    Some(("md5", md5)) => md5_hash = Some(md5.to_owned()),
+                    _ => {}
+                }
+            }
+        }
+
+        Self {
+            generation: Self::number(headers, "x-goog-generation"),
+            metageneration: Self::number(headers, "x-goog-metageneration"),
+            stored_content_encoding: Self::string(headers, "x-goog-stored-content-encoding"),
+            stored_content_length: Self::number(headers, "x-goog-stored-content-length"),
+            crc32c,
+            md5_hash,
+            etag: Self::string(headers, http::header::ETAG.as_str()),
+            content_type: Self::string(headers, http::header::CONTENT_TYPE.as_str()),
+        }
+    }
+}
+
+impl From<&DownloadHeaders> for ObjectHashes {
+    /// Note the hashes are always for the complete stored object, so can't be
+    /// used to verify a ranged download, or one that was transcoded
+    fn from(headers: &DownloadHeaders) -> Self {
+        Self {
+            crc32c: headers.crc32c.clone(),
+            md5_hash: headers.md5_hash.clone(),
+        }
+    }
 }
 
 pub struct DownloadObjectResponse {
     buffer: bytes::Bytes,
+    content_range: Option<ContentRange>,
+    headers: DownloadHeaders,
 }
 
 impl DownloadObjectResponse {
     pub fn consume(self) -> bytes::Bytes {
         self.buffer
     }
+
+    /// The range of the object contained in the response, only present if
+    /// the server responded with `206 Partial Content` to a ranged download
+    pub fn content_range(&self) -> Option<ContentRange> {
+        self.content_range
+    }
+
+    /// The object information that was sent in the response headers
+    pub fn headers(&self) -> &DownloadHeaders {
+        &self.headers
+    }
 }
 
 impl ApiResponse<bytes::Bytes> for DownloadObjectResponse {}
@@ -42,9 +261,25 @@ impl TryFrom<http::Response<bytes::Bytes>> for DownloadObjectResponse {
     type Error = Error;
 
     fn try_from(response: http::Response<bytes::Bytes>) -> Result<Self, Self::Error> {
-        let (_parts, body) = response.into_parts();
+        let (parts, body) = response.into_parts();
+
+        let content_range = if parts.status == http::StatusCode::PARTIAL_CONTENT {
+            let value = parts
+                .headers
+                .get(http::header::CONTENT_RANGE)
+                .ok_or(Error::UnknownHeader(http::header::CONTENT_RANGE))?;
+            Some(ContentRange::try_from(value)?)
+        } else {
+            None
+        };
 
-        Ok(Self { buffer: body })
+        let headers = DownloadHeaders::from(&parts.headers);
+
+        Ok(Self {
+            buffer: body,
+            content_range,
+            headers,
+        })
     }
 }
 
@@ -69,7 +304,8 @@ impl io::Read for DownloadObjectResponse {
 }
 
 impl super::Object {
-    /// Downloads an object
+    /// Downloads an object, or only a range of it if
+    /// [`DownloadObjectOptional::range`] is set.
     ///
     /// Required IAM Permissions: `storage.objects.get`, `storage.objects.getIamPolicy`*
     ///
@@ -89,13 +325,23 @@ impl super::Object {
         );
 
         let query = optional.unwrap_or_default();
+        let range = query.range;
+        let encryption_key = query.encryption_key;
         let query_params = serde_urlencoded::to_string(query)?;
         if !query_params.is_empty() {
             uri.push('&');
             uri.push_str(&query_params);
         }
 
-        let req_builder = http::Request::builder();
+        let mut req_builder = http::Request::builder();
+
+        if let Some(range) = range {
+            req_builder = req_builder.header(http::header::RANGE, range.to_header()?);
+        }
+
+        if let Some(key) = encryption_key {
+            req_builder = key.apply(req_builder);
+        }
 
         Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
     }
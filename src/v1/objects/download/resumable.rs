@@ -0,0 +1,201 @@
+use super::*;
+use crate::{
+    objects::{EncryptionKey, Object},
+    types::{BucketName, ObjectName},
+};
+
+/// A sans-io state machine used to download an object across multiple
+/// requests, so that if a download is interrupted, only the bytes that
+/// haven't been received yet need to be requested again.
+///
+/// Every request is for the generation of the object that was specified up
+/// front, or that was returned by the first response, so data from different
+/// versions of the object is never spliced together, and noncurrent versions
+/// can be downloaded.
+///
+/// Note that objects stored with `Content-Encoding: gzip` that are
+/// decompressively transcoded can't be downloaded in ranges, see
+/// [`ResumableDownload::is_transcoded`].
+///
+/// 1. Send the request from [`ResumableDownload::next_request`]
+/// 1. Pass the response to [`ResumableDownload::on_response`]
+/// 1. Pass every chunk of the body to [`ResumableDownload::on_data`] as it is received
+/// 1. If the download was interrupted, and is not yet [complete](ResumableDownload::is_complete),
+///    start from the beginning
+#[derive(Clone, Debug)]
+pub struct ResumableDownload {
+    object: Object,
+    bucket: String,
+    name: String,
+    user_project: Option<String>,
+    encryption_key: Option<EncryptionKey>,
+    generation: Option<i64>,
+    received: u64,
+    total: Option<u64>,
+    transcoded: bool,
+}
+
+impl ResumableDownload {
+    /// The number of bytes of the object that have been received so far
+    pub fn received(&self) -> u64 {
+        self.received
+    }
+
+    /// The generation of the object being downloaded, known once the first
+    /// response has been received, unless it was specified up front
+    pub fn generation(&self) -> Option<i64> {
+        self.generation
+    }
+
+    /// The total size of the object, known once the first response has
+    /// been received
+    pub fn total_size(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Whether GCS decompressively transcoded the response, in which case
+    /// the download can't be resumed, as the length of the response differs
+    /// from the stored object and ranges aren't supported
+    pub fn is_transcoded(&self) -> bool {
+        self.transcoded
+    }
+
+    /// Whether the entire object has been received
+    pub fn is_complete(&self) -> bool {
+        self.total.is_some_and(|total| self.received >= total)
+    }
+
+    /// Sets the project to be billed for the requests. Required for Requester
+    /// Pays buckets.
+    pub fn with_user_project(mut self, user_project: impl Into<String>) -> Self {
+        self.user_project = Some(user_project.into());
+        self
+    }
+
+    /// Sets the customer-supplied key the object is encrypted with, which is
+    /// sent with every request
+    pub fn with_encryption_key(mut self, key: EncryptionKey) -> Self {
+        self.encryption_key = Some(key);
+        self
+    }
+
+    /// Creates the request for the remainder of the object that has not yet
+    /// been received
+    pub fn next_request(&self) -> Result<http::Request<std::io::Empty>, Error> {
+        let id = (
+            &BucketName::non_validated(&self.bucket),
+            &ObjectName::non_validated(&self.name),
+        );
+
+        self.object.download(
+            &id,
+            Some(DownloadObjectOptional {
+                generation: self.generation,
+                conditionals: Conditionals {
+                    if_generation_match: self.generation,
+                    ..Default::default()
+                },
+                user_project: self.user_project.as_deref(),
+                range: (self.received > 0).then_some(ByteRange::From(self.received)),
+                encryption_key: self.encryption_key.as_ref(),
+                ..Default::default()
+            }),
+        )
+    }
+
+    /// Updates the state with the status and headers of the response to the
+    /// last request from [`ResumableDownload::next_request`]. Fails if the
+    /// response is an error, or if it doesn't contain the expected part of
+    /// the expected generation of the object.
+    pub fn on_response<B>(&mut self, response: &http::Response<B>) -> Result<(), Error> {
+        let status = response.status();
+        let resumed = self.received > 0;
+
+        if !status.is_success() || (resumed && status != http::StatusCode::PARTIAL_CONTENT) {
+            return Err(Error::from(status));
+        }
+
+        let headers = DownloadHeaders::from(response.headers());
+
+        if let Some(found) = headers.generation {
+            match self.generation {
+                Some(expected) if expected != found => {
+                    return Err(Error::GenerationMismatch { expected, found });
+                }
+                _ => self.generation = Some(found),
+            }
+        }
+
+        let total = if status == http::StatusCode::PARTIAL_CONTENT {
+            let value = response
+                .headers()
+                .get(http::header::CONTENT_RANGE)
+                .ok_or(Error::UnknownHeader(http::header::CONTENT_RANGE))?;
+            let range = ContentRange::try_from(value)?;
+
+            if range.start != self.received {
+                return Err(Error::OpaqueHeaderValue(value.clone()));
+            }
+
+            range.total
+        } else {
+            let content_length =
+                crate::util::get_content_length(response.headers()).map(|l| l as u64);
+
+            // The stored length is that of the compressed object, so if it
+            // differs from the response, GCS decompressed the object
+            self.transcoded = headers
+                .stored_content_length
+                .zip(content_length)
+                .is_some_and(|(stored, content)| stored != content)
+                || (headers.stored_content_encoding.as_deref() == Some("gzip")
+                    && response
+                        .headers()
+                        .get(http::header::CONTENT_ENCODING)
+                        .is_none());
+
+            content_length
+        };
+
+        if total.is_some() {
+            self.total = total;
+        }
+
+        Ok(())
+    }
+
+    /// Updates the number of bytes received with a chunk of the response body
+    pub fn on_data(&mut self, data: &[u8]) {
+        self.received += data.len() as u64;
+    }
+}
+
+impl Object {
+    /// Starts a [`ResumableDownload`] of an object. If `generation` is not
+    /// specified, the download is pinned to the generation of the object that
+    /// is live when the first response is received.
+    ///
+    /// Required IAM Permissions: `storage.objects.get`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/get)
+    pub fn resumable_download<'a, OID>(
+        &self,
+        id: &OID,
+        generation: Option<i64>,
+    ) -> ResumableDownload
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        ResumableDownload {
+            object: self.clone(),
+            bucket: id.bucket().to_string(),
+            name: id.object().to_string(),
+            user_project: None,
+            encryption_key: None,
+            generation,
+            received: 0,
+            total: None,
+            transcoded: false,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use super::*;
+use crate::{
+    hashing::{Crc32c, decode_crc32c, encode_crc32c},
+    objects::{Metadata, Object},
+    types::{BucketName, ObjectName},
+};
+
+/// A single ranged download of a [`SlicedDownload`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DownloadSlice {
+    /// The offset of the slice in the object
+    pub offset: u64,
+    /// The length of the slice
+    pub length: u64,
+}
+
+#[derive(Default)]
+struct SliceState {
+    crc32c: Crc32c,
+    received: u64,
+}
+
+/// A sans-io planner used to download a large object as several slices in
+/// parallel, verifying the `CRC32c` checksum of the complete object once
+/// every slice has been received.
+///
+/// Every slice is pinned to the generation of the object in the [`Metadata`]
+/// used to create the planner, so slices of different versions of the object
+/// can't be combined.
+///
+/// 1. Send the request from [`SlicedDownload::request`] for every slice, in
+///    any order
+/// 1. Pass each response to [`SlicedDownload::on_slice_response`], which
+///    also discards any data received for a previous attempt at the slice
+/// 1. Write the body of each response at the slice's offset, eg. with
+///    [`SlicedDownload::slice_buffer`], and pass it to [`SlicedDownload::on_slice_data`],
+///    either all at once or as it is received
+/// 1. Once every slice is complete, check the object with [`SlicedDownload::verify`]
+pub struct SlicedDownload {
+    object: Object,
+    bucket: String,
+    name: String,
+    generation: i64,
+    size: u64,
+    crc32c: Option<String>,
+    user_project: Option<String>,
+    slices: Vec<DownloadSlice>,
+    states: Vec<SliceState>,
+}
+
+impl SlicedDownload {
+    /// The slices the object is split into
+    pub fn slices(&self) -> &[DownloadSlice] {
+        &self.slices
+    }
+
+    /// The total size of the object
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Sets the project to be billed for the requests. Required for Requester
+    /// Pays buckets.
+    pub fn with_user_project(mut self, user_project: impl Into<String>) -> Self {
+        self.user_project = Some(user_project.into());
+        self
+    }
+
+    /// Creates the ranged download request for the slice at `index`.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn request(&self, index: usize) -> Result<http::Request<std::io::Empty>, Error> {
+        let slice = self.slices[index];
+
+        self.object.download(
+            &(
+                &BucketName::non_validated(&self.bucket),
+                &ObjectName::non_validated(&self.name),
+            ),
+            Some(DownloadObjectOptional {
+                generation: Some(self.generation),
+                user_project: self.user_project.as_deref(),
+                range: Some(ByteRange::Bounded {
+                    start: slice.offset,
+                    end: slice.offset + slice.length - 1,
+                }),
+                ..Default::default()
+            }),
+        )
+    }
+
+    /// Checks that the response to the request for the slice at `index`
+    /// contains exactly that slice of the expected generation of the object,
+    /// and resets the slice so that a slice that was interrupted can be
+    /// retried from its beginning.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn on_slice_response<B>(
+        &mut self,
+        index: usize,
+        response: &http::Response<B>,
+    ) -> Result<(), Error> {
+        let slice = self.slices[index];
+        let status = response.status();
+
+        // A slice that covers the entire object may be returned in full
+        let is_full = status == http::StatusCode::OK && slice.length == self.size;
+        if !is_full && status != http::StatusCode::PARTIAL_CONTENT {
+            return Err(Error::from(status));
+        }
+
+        let headers = DownloadHeaders::from(response.headers());
+        if let Some(found) = headers.generation
+            && found != self.generation
+        {
+            return Err(Error::GenerationMismatch {
+                expected: self.generation,
+                found,
+            });
+        }
+
+        if !is_full {
+            let value = response
+                .headers()
+                .get(http::header::CONTENT_RANGE)
+                .ok_or(Error::UnknownHeader(http::header::CONTENT_RANGE))?;
+            let range = ContentRange::try_from(value)?;
+
+            if range.start != slice.offset || range.size() != slice.length {
+                return Err(Error::OpaqueHeaderValue(value.clone()));
+            }
+        }
+
+        self.states[index] = SliceState::default();
+        Ok(())
+    }
+
+    /// Returns the part of a buffer holding the entire object that the slice
+    /// at `index` should be written to.
+    ///
+    /// Panics if `index` is out of bounds, or the buffer is smaller than the
+    /// object.
+    pub fn slice_buffer<'b>(&self, index: usize, buffer: &'b mut [u8]) -> &'b mut [u8] {
+        let slice = self.slices[index];
+        &mut buffer[slice.offset as usize..(slice.offset + slice.length) as usize]
+    }
+
+    /// Adds the next chunk of data received for the slice at `index`, which
+    /// can be called multiple times as the body of the slice is streamed.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn on_slice_data(&mut self, index: usize, data: &[u8]) -> Result<(), Error> {
+        let slice = self.slices[index];
+        let state = &mut self.states[index];
+
+        let received = state.received + data.len() as u64;
+        if received > slice.length {
+            return Err(Error::SliceLengthMismatch {
+                expected: slice.length,
+                found: received,
+            });
+        }
+
+        state.crc32c.update(data);
+        state.received = received;
+        Ok(())
+    }
+
+    /// Whether every slice has been completely received
+    pub fn is_complete(&self) -> bool {
+        self.slices
+            .iter()
+            .zip(&self.states)
+            .all(|(slice, state)| state.received == slice.length)
+    }
+
+    /// Verifies the combined `CRC32c` checksum of every slice against the
+    /// object's metadata
+    pub fn verify(&self) -> Result<(), Error> {
+        if !self.is_complete() {
+            return Err(Error::InsufficientData);
+        }
+
+        let expected = self.crc32c.as_ref().ok_or(Error::MissingHash)?;
+        let crc = self
+            .slices
+            .iter()
+            .zip(&self.states)
+            .fold(Crc32c::checksum(&[]), |crc, (slice, state)| {
+                Crc32c::combine(crc, state.crc32c.finalize(), slice.length)
+            });
+
+        if decode_crc32c(expected) != Some(crc) {
+            return Err(Error::HashMismatch {
+                algorithm: "crc32c",
+                expected: expected.clone(),
+                calculated: encode_crc32c(crc),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Object {
+    /// Plans a [`SlicedDownload`] of the object described by `metadata`, which
+    /// must contain the object's bucket, name, size and generation, split into
+    /// at most `slice_count` slices. An empty object has no slices, as it
+    /// can't be requested with a range, so is already complete.
+    pub fn sliced_download(
+        &self,
+        metadata: &Metadata,
+        slice_count: usize,
+    ) -> Result<SlicedDownload, Error> {
+        let bucket = metadata
+            .bucket
+            .clone()
+            .ok_or(Error::MissingMetadata("bucket"))?;
+        let name = metadata
+            .name
+            .clone()
+            .ok_or(Error::MissingMetadata("name"))?;
+        let size = metadata.size.ok_or(Error::MissingMetadata("size"))?;
+        let generation = metadata
+            .generation
+            .ok_or(Error::MissingMetadata("generation"))?;
+
+        if slice_count == 0 {
+            return Err(Error::InvalidSliceCount);
+        }
+
+        let slice_count = (slice_count as u64).min(size);
+        let slice_size = size.div_ceil(slice_count.max(1));
+
+        let slices: Vec<_> = (0..slice_count)
+            .map(|i| i * slice_size)
+            .take_while(|offset| *offset < size)
+            .map(|offset| DownloadSlice {
+                offset,
+                length: (size - offset).min(slice_size),
+            })
+            .collect();
+
+        let states = slices.iter().map(|_| SliceState::default()).collect();
+
+        Ok(SlicedDownload {
+            object: self.clone(),
+            bucket,
+            name,
+            generation,
+            size,
+            crc32c: metadata.crc32c.clone(),
+            user_project: None,
+            slices,
+            states,
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    error::Error,
+    signing::{DigestAlgorithm, DigestCalulator},
+};
+use http::{HeaderValue, request::Builder};
+use std::fmt;
+
+/// A [customer-supplied encryption key](https://cloud.google.com/storage/docs/encryption/customer-supplied-keys)
+/// (CSEK) used to encrypt an object with AES-256. GCS doesn't store the key,
+/// so it must be supplied with every request that reads or writes the
+/// object's data.
+#[derive(Clone)]
+pub struct EncryptionKey {
+    key: HeaderValue,
+    key_sha256: String,
+}
+
+impl EncryptionKey {
+    /// Creates an encryption key from a raw AES-256 key, using the digest
+    /// calculator to calculate the SHA-256 hash GCS uses to verify the key
+    /// was transmitted correctly
+    pub fn new<D: DigestCalulator>(key: &[u8; 32], digest: &D) -> Result<Self, Error> {
+        let mut key_sha256 = [0u8; 32];
+        digest.digest(DigestAlgorithm::Sha256, key, &mut key_sha256);
+
+        let mut encoded_key = HeaderValue::from_str(&data_encoding::BASE64.encode(key))?;
+        encoded_key.set_sensitive(true);
+
+        Ok(Self {
+            key: encoded_key,
+            key_sha256: data_encoding::BASE64.encode(&key_sha256),
+        })
+    }
+
+    /// The base64 encoded SHA-256 hash of the key, which can be compared with
+    /// [`super::CustomerEncryption::key_sha256`] to find which key an object
+    /// was encrypted with
+    pub fn key_sha256(&self) -> &str {
+        &self.key_sha256
+    }
+
+    /// Adds the headers for the key used to encrypt the object being read or written
+    pub(crate) fn apply(&self, req_builder: Builder) -> Builder {
+        req_builder
+            .header("x-goog-encryption-algorithm", "AES256")
+            .header("x-goog-encryption-key", self.key.clone())
+            .header("x-goog-encryption-key-sha256", self.key_sha256.as_str())
+    }
+
+    /// Adds the headers for the key used to encrypt the source object of a rewrite
+    pub(crate) fn apply_source(&self, req_builder: Builder) -> Builder {
+        req_builder
+            .header("x-goog-copy-source-encryption-algorithm", "AES256")
+            .header("x-goog-copy-source-encryption-key", self.key.clone())
+            .header(
+                "x-goog-copy-source-encryption-key-sha256",
+                self.key_sha256.as_str(),
+            )
+    }
+}
+
+impl fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Never print the key itself
+        f.debug_struct("EncryptionKey")
+            .field("key_sha256", &self.key_sha256)
+            .finish_non_exhaustive()
+    }
+}
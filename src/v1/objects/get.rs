@@ -1,3 +1,4 @@
+use super::EncryptionKey;
 use crate::{
     common::{Conditionals, Projection, StandardQueryParameters},
     error::Error,
@@ -23,6 +24,14 @@ pub struct GetObjectOptional<'a> {
     /// The project to be billed for this request. Required for Requester Pays buckets.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_project: Option<&'a str>,
+    /// If true, gets the soft-deleted object with the specified `generation`,
+    /// which is then required.
+    #[serde(skip_serializing_if = "crate::util::if_false")]
+    pub soft_deleted: bool,
+    /// The customer-supplied key the object is encrypted with, required to
+    /// get the object's hashes, sent as the `x-goog-encryption-*` headers.
+    #[serde(skip)]
+    pub encryption_key: Option<&'a EncryptionKey>,
 }
 
 pub struct GetObjectResponse {
@@ -66,13 +75,18 @@ impl super::Object {
         );
 
         let query = optional.unwrap_or_default();
+        let encryption_key = query.encryption_key;
         let query_params = serde_urlencoded::to_string(query)?;
         if !query_params.is_empty() {
             uri.push('&');
             uri.push_str(&query_params);
         }
 
-        let req_builder = http::Request::builder();
+        let mut req_builder = http::Request::builder();
+
+        if let Some(key) = encryption_key {
+            req_builder = key.apply(req_builder);
+        }
 
         Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
     }
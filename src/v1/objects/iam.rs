@@ -0,0 +1,118 @@
+use crate::{common::StandardQueryParameters, error::Error, iam::Policy, types::ObjectIdentifier};
+
+/// Optional parameters for object IAM policy operations
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectIamOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// If present, selects a specific revision of this object
+    /// (as opposed to the latest version, the default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation: Option<i64>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+impl super::Object {
+    /// Returns an IAM policy for the specified object.
+    ///
+    /// Required IAM Permissions: `storage.objects.getIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/getIamPolicy)
+    pub fn get_iam_policy<'a, OID>(
+        &self,
+        id: &OID,
+        optional: Option<ObjectIamOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let mut uri =
+            crate::__make_obj_url!("https://{}/storage/v1/b/{}/o/{}/iam", self.authority, id);
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
+    }
+
+    /// Updates an IAM policy for the specified object. The policy's etag should
+    /// be the one retrieved via [`get_iam_policy`](#method.get_iam_policy) so
+    /// that concurrent modifications of the policy are detected.
+    ///
+    /// Required IAM Permissions: `storage.objects.setIamPolicy`
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/setIamPolicy)
+    pub fn set_iam_policy<'a, OID>(
+        &self,
+        id: &OID,
+        policy: &Policy,
+        optional: Option<ObjectIamOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let mut uri =
+            crate::__make_obj_url!("https://{}/storage/v1/b/{}/o/{}/iam", self.authority, id);
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        let body = serde_json::to_vec(policy)?;
+        let len = body.len();
+        let body = std::io::Cursor::new(body);
+
+        Ok(req_builder
+            .method("PUT")
+            .header("content-type", "application/json")
+            .header("content-length", len)
+            .uri(uri)
+            .body(body)?)
+    }
+
+    /// Tests a set of permissions on the given object to see which, if any,
+    /// are held by the caller.
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/testIamPermissions)
+    pub fn test_iam_permissions<'a, OID>(
+        &self,
+        id: &OID,
+        permissions: &[&str],
+        optional: Option<ObjectIamOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let mut uri = crate::__make_obj_url!(
+            "https://{}/storage/v1/b/{}/o/{}/iam/testPermissions",
+            self.authority,
+            id
+        );
+        crate::iam::push_permissions(&mut uri, permissions)?;
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('&');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
+    }
+}
@@ -1,6 +1,8 @@
+use super::EncryptionKey;
 use crate::{
     common::{Conditionals, PredefinedAcl, Projection, StandardQueryParameters},
     error::{self, Error},
+    hashing::ObjectHashes,
     response::ApiResponse,
     types::ObjectIdentifier,
 };
@@ -44,6 +46,15 @@ pub struct InsertObjectOptional<'a> {
     /// The project to be billed for this request. Required for Requester Pays buckets.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_project: Option<&'a str>,
+    /// The hashes of the content being uploaded. If set, GCS verifies the
+    /// uploaded content against them and rejects the upload if it was
+    /// corrupted in transit.
+    #[serde(skip)]
+    pub hashes: Option<&'a ObjectHashes>,
+    /// The customer-supplied key the object is encrypted with, sent as the
+    /// `x-goog-encryption-*` headers.
+    #[serde(skip)]
+    pub encryption_key: Option<&'a EncryptionKey>,
 }
 
 /// The response from an [`insert`](#method.insert) request is the object [metadata](https://cloud.google.com/storage/docs/json_api/v1/objects#resource)
@@ -68,6 +79,31 @@ where
     }
 }
 
+/// Serializes the metadata for a multipart or resumable insert, which are
+/// verified against the hashes in the metadata, but we don't want to override
+/// any the user has already set there
+fn serialize_metadata(
+    metadata: &super::Metadata,
+    hashes: Option<&ObjectHashes>,
+) -> Result<Vec<u8>, Error> {
+    let Some(hashes) = hashes else {
+        return Ok(serde_json::to_vec(metadata)?);
+    };
+
+    let mut value = serde_json::to_value(metadata)?;
+    if let Some(fields) = value.as_object_mut() {
+        for (key, hash) in [("crc32c", &hashes.crc32c), ("md5Hash", &hashes.md5_hash)] {
+            if let Some(hash) = hash {
+                fields
+                    .entry(key)
+                    .or_insert_with(|| serde_json::Value::from(hash.as_str()));
+            }
+        }
+    }
+
+    Ok(serde_json::to_vec(&value)?)
+}
+
 impl super::Object {
     /// Stores a new object and metadata.
     ///
@@ -99,7 +135,7 @@ impl super::Object {
 
         let query = optional.unwrap_or_default();
 
-        let req_builder = http::Request::builder()
+        let mut req_builder = http::Request::builder()
             .header(
                 http::header::CONTENT_TYPE,
                 http::header::HeaderValue::from_str(
@@ -109,6 +145,16 @@ impl super::Object {
             )
             .header(http::header::CONTENT_LENGTH, length);
 
+        if let Some(hashes) = query.hashes
+            && let Some(value) = hashes.to_header_value()?
+        {
+            req_builder = req_builder.header("x-goog-hash", value);
+        }
+
+        if let Some(key) = query.encryption_key {
+            req_builder = key.apply(req_builder);
+        }
+
         let query_params = serde_urlencoded::to_string(query)?;
         if !query_params.is_empty() {
             uri.push('&');
@@ -1,6 +1,7 @@
 use super::InsertObjectOptional;
 use crate::{
     Error,
+    hashing::ObjectHashes,
     objects::{Metadata, Object},
     types::{BucketName, ObjectName},
 };
@@ -54,12 +55,21 @@ impl<B> Multipart<B> {
     /// sent as an HTTP request body, the body will need to implement `std::io::Read`
     /// to be able to be used as intended.
     pub fn wrap(body: B, body_length: u64, metadata: &Metadata) -> Result<Self, Error> {
+        Self::wrap_with_hashes(body, body_length, metadata, None)
+    }
+
+    fn wrap_with_hashes(
+        body: B,
+        body_length: u64,
+        metadata: &Metadata,
+        hashes: Option<&ObjectHashes>,
+    ) -> Result<Self, Error> {
         use bytes::BufMut;
 
         const CT_HN: &[u8] = b"content-type: ";
 
         // I wonder if this counts as sansio...
-        let serialized_metadata = serde_json::to_vec(metadata)?;
+        let serialized_metadata = super::serialize_metadata(metadata, hashes)?;
         let content_type = metadata
             .content_type
             .as_deref()
@@ -229,15 +239,19 @@ impl Object {
 
         let query = optional.unwrap_or_default();
 
-        let multipart = Multipart::wrap(content, length, metadata)?;
+        let multipart = Multipart::wrap_with_hashes(content, length, metadata, query.hashes)?;
 
-        let req_builder = http::Request::builder()
+        let mut req_builder = http::Request::builder()
             .header(
                 http::header::CONTENT_TYPE,
                 http::header::HeaderValue::from_static("multipart/related; boundary=tame_gcs"),
             )
             .header(http::header::CONTENT_LENGTH, multipart.total_len());
 
+        if let Some(key) = query.encryption_key {
+            req_builder = key.apply(req_builder);
+        }
+
         let query_params = serde_urlencoded::to_string(query)?;
         if !query_params.is_empty() {
             uri.push('&');
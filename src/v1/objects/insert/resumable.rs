@@ -1,5 +1,13 @@
 use super::*;
-use crate::objects::{Metadata, Object};
+use crate::{
+    hashing::ObjectHashes,
+    objects::{Metadata, Object},
+    types::{BucketName, ObjectName},
+};
+
+mod upload;
+
+pub use upload::*;
 
 #[derive(Clone)]
 pub struct ResumableSession(pub http::Uri);
@@ -40,8 +48,13 @@ where
 }
 
 pub enum ResumableInsertResponseMetadata {
+    /// The number of bytes persisted so far, the upload is not yet complete
     PartialSize(u64),
+    /// The upload is complete
     Complete(Box<Metadata>),
+    /// The session has expired or doesn't exist (`404` or `410`), so the
+    /// upload can't be resumed and needs to be restarted with a new session
+    SessionExpired,
 }
 
 /// The response from an [`resumable_upload`](#method.resumable_upload) request
@@ -63,6 +76,8 @@ impl ResumableInsertResponse {
         // See https://cloud.google.com/storage/docs/json_api/v1/status-codes#308_Resume_Incomplete
             || status.eq(&http::StatusCode::OK)
             || status.eq(&http::StatusCode::CREATED)
+            || status.eq(&http::StatusCode::NOT_FOUND)
+            || status.eq(&http::StatusCode::GONE)
         {
             Self::try_from(response)
         } else {
@@ -72,17 +87,16 @@ impl ResumableInsertResponse {
                 .headers()
                 .get(http::header::CONTENT_TYPE)
                 .and_then(|ct| ct.to_str().ok())
+                && ct.starts_with("text/plain")
+                && !response.body().as_ref().is_empty()
+                && let Ok(message) = std::str::from_utf8(response.body().as_ref())
             {
-                if ct.starts_with("text/plain") && !response.body().as_ref().is_empty() {
-                    if let Ok(message) = std::str::from_utf8(response.body().as_ref()) {
-                        let api_err = error::ApiError {
-                            code: status.into(),
-                            message: message.to_owned(),
-                            errors: vec![],
-                        };
-                        return Err(Error::Api(api_err));
-                    }
-                }
+                let api_err = error::ApiError {
+                    code: status.into(),
+                    message: message.to_owned(),
+                    errors: vec![],
+                };
+                return Err(Error::Api(api_err));
             }
             Err(Error::from(response.status()))
         }
@@ -124,11 +138,22 @@ where
                     },
                     Err(_err) => Err(Error::OpaqueHeaderValue(range_val.clone())),
                 },
-                None => Err(Error::UnknownHeader(http::header::RANGE)),
+                // No Range header means that no bytes have been persisted yet
+                None => {
+                    return Ok(Self {
+                        metadata: ResumableInsertResponseMetadata::PartialSize(0),
+                    });
+                }
             }?;
             Ok(Self {
                 metadata: ResumableInsertResponseMetadata::PartialSize(end_pos + 1),
             })
+        } else if response.status().eq(&http::StatusCode::NOT_FOUND)
+            || response.status().eq(&http::StatusCode::GONE)
+        {
+            Ok(Self {
+                metadata: ResumableInsertResponseMetadata::SessionExpired,
+            })
         } else {
             let (_parts, body) = response.into_parts();
             let metadata = Box::new(serde_json::from_slice(body.as_ref())?);
@@ -222,6 +247,97 @@ impl Object {
         Ok(req_builder.method("POST").uri(uri).body(())?)
     }
 
+    /// Initiates a resumable upload session, sending the object's `metadata`
+    /// in the body of the request, the same as [`Object::insert_multipart`].
+    ///
+    /// * `upload_length` - The total size of the object, if known. If set,
+    ///   GCS rejects the upload if it doesn't match the amount of data uploaded.
+    /// * `origin` - The origin of the browser that will perform the upload,
+    ///   required for [CORS](https://cloud.google.com/storage/docs/cross-origin)
+    ///   uploads that use the session URI from a different origin.
+    ///
+    /// The content type of the upload is taken from
+    /// [`InsertObjectOptional::content_type`], falling back to the content type
+    /// in the `metadata`. If [`InsertObjectOptional::hashes`] are set, they are
+    /// added to the `metadata` so that GCS verifies the completed upload.
+    ///
+    /// Required IAM Permissions: `storage.objects.create`, `storage.objects.delete`
+    ///
+    /// Note: `storage.objects.delete` is only needed if an object with the same
+    /// name already exists.
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/performing-resumable-uploads#initiate-session)
+    pub fn resumable_insert_init_with_metadata(
+        &self,
+        bucket: &BucketName<'_>,
+        metadata: &Metadata,
+        upload_length: Option<u64>,
+        origin: Option<&str>,
+        optional: Option<InsertObjectOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        // Since the user can specify the name in the metadata, we just always
+        // use that
+        let name = metadata
+            .name
+            .as_deref()
+            .ok_or(Error::MissingMetadata("name"))?;
+        ObjectName::try_from(name)?;
+
+        let mut uri = format!(
+            "https://{}/upload/storage/v1/b/{}/o?uploadType=resumable",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(bucket.as_ref(), crate::util::PATH_ENCODE_SET,),
+        );
+
+        let query = optional.unwrap_or_default();
+
+        let body = super::serialize_metadata(metadata, query.hashes)?;
+        let content_type = query
+            .content_type
+            .or(metadata.content_type.as_deref())
+            .unwrap_or("application/octet-stream");
+
+        let mut req_builder = http::Request::builder()
+            .header(
+                http::header::CONTENT_TYPE,
+                http::header::HeaderValue::from_static("application/json; charset=UTF-8"),
+            )
+            .header(http::header::CONTENT_LENGTH, body.len())
+            .header(
+                http::header::HeaderName::from_static("x-upload-content-type"),
+                http::header::HeaderValue::from_str(content_type).map_err(http::Error::from)?,
+            );
+
+        if let Some(upload_length) = upload_length {
+            req_builder = req_builder.header(
+                http::header::HeaderName::from_static("x-upload-content-length"),
+                upload_length,
+            );
+        }
+
+        if let Some(origin) = origin {
+            req_builder = req_builder.header(
+                http::header::ORIGIN,
+                http::header::HeaderValue::from_str(origin).map_err(http::Error::from)?,
+            );
+        }
+
+        if let Some(key) = query.encryption_key {
+            req_builder = key.apply(req_builder);
+        }
+
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('&');
+            uri.push_str(&query_params);
+        }
+
+        Ok(req_builder
+            .method("POST")
+            .uri(uri)
+            .body(std::io::Cursor::new(body))?)
+    }
+
     /// Cancels an incomplete resumable upload and prevent any further action for
     /// `session_uri`, which should have been obtained using [`Object::init_resumable_insert`]
     ///
@@ -252,6 +368,9 @@ impl Object {
     /// chunk. If not, the server will not accept all bytes sent in the request.
     /// Also, it is recommended to use at least 8MiB.
     ///
+    /// [`ResumableUpload`] can be used to track the `Content-Range` of each
+    /// chunk, and to send the hashes of the complete object with the final one.
+    ///
     /// [Complete API Documentation](https://cloud.google.com/storage/docs/performing-resumable-uploads#chunked-upload)
     pub fn resumable_append<B>(
         session: ResumableSession,
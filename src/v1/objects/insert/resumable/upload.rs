@@ -0,0 +1,201 @@
+use super::*;
+
+/// Every chunk of a resumable upload, except the last one, must be a multiple
+/// of this size.
+pub const RESUMABLE_CHUNK_ALIGNMENT: u64 = 256 * 1024;
+
+/// The default size of the chunks of a [`ResumableUpload`], the minimum size
+/// recommended by GCS.
+pub const DEFAULT_RESUMABLE_CHUNK_SIZE: u64 = 32 * RESUMABLE_CHUNK_ALIGNMENT;
+
+/// A sans-io state machine used to upload an object in chunks to a
+/// [`ResumableSession`], keeping track of the bytes GCS has persisted so
+/// that an upload can be recovered after a failure.
+///
+/// 1. Read the bytes at [`ResumableUpload::next_range`] from the source
+/// 1. Send the request from [`ResumableUpload::append`] with those bytes
+/// 1. Pass the parsed response to [`ResumableUpload::on_response`], which
+///    returns the object's metadata once the upload is complete
+/// 1. If a request fails, send the request from [`ResumableUpload::status`]
+///    and pass its response to [`ResumableUpload::on_response`] to learn how
+///    many bytes GCS actually persisted, then start from the beginning
+pub struct ResumableUpload {
+    session: ResumableSession,
+    chunk_size: u64,
+    total: Option<u64>,
+    persisted: u64,
+    complete: bool,
+    hashes: Option<ObjectHashes>,
+}
+
+impl ResumableUpload {
+    /// Creates an upload to the specified session. The `total` size of the
+    /// object can be left unspecified if it isn't known up front, eg. when
+    /// streaming, in which case it is determined by the final chunk.
+    pub fn new(session: ResumableSession, total: Option<u64>) -> Self {
+        Self {
+            session,
+            chunk_size: DEFAULT_RESUMABLE_CHUNK_SIZE,
+            total,
+            persisted: 0,
+            complete: false,
+            hashes: None,
+        }
+    }
+
+    /// Sets the maximum size of each chunk, rounded up to the nearest multiple
+    /// of [`RESUMABLE_CHUNK_ALIGNMENT`]
+    pub fn with_chunk_size(mut self, chunk_size: u64) -> Self {
+        self.chunk_size =
+            chunk_size.div_ceil(RESUMABLE_CHUNK_ALIGNMENT).max(1) * RESUMABLE_CHUNK_ALIGNMENT;
+        self
+    }
+
+    /// Sets the hashes of the complete object, which are sent with the final
+    /// chunk so that GCS rejects the upload if it was corrupted
+    pub fn with_hashes(mut self, hashes: ObjectHashes) -> Self {
+        self.hashes = Some(hashes);
+        self
+    }
+
+    /// The session being uploaded to
+    pub fn session(&self) -> &ResumableSession {
+        &self.session
+    }
+
+    /// The number of bytes that GCS has persisted
+    pub fn persisted(&self) -> u64 {
+        self.persisted
+    }
+
+    /// The total size of the object, if known
+    pub fn total_size(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Whether the upload has completed
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// The range of bytes of the object that should be sent in the next call
+    /// to [`ResumableUpload::append`]. If the total size of the object isn't
+    /// known, fewer bytes can be sent if it's the final chunk.
+    pub fn next_range(&self) -> std::ops::Range<u64> {
+        let end = self.persisted + self.chunk_size;
+        let end = self.total.map_or(end, |total| end.min(total));
+
+        self.persisted..end
+    }
+
+    /// Creates the request to upload the next chunk of the object, which must
+    /// start at the beginning of [`ResumableUpload::next_range`]. Every chunk
+    /// except the last must be a multiple of [`RESUMABLE_CHUNK_ALIGNMENT`].
+    ///
+    /// `is_last` is only needed when the total size of the object isn't
+    /// known, otherwise the last chunk is the one that ends at the total size.
+    /// Once the last chunk has been appended the total size is known, and is
+    /// used by [`ResumableUpload::status`].
+    pub fn append<B>(
+        &mut self,
+        content: B,
+        length: u64,
+        is_last: bool,
+    ) -> Result<http::Request<B>, Error> {
+        let start = self.persisted;
+        let end = start + length;
+
+        let total = match self.total {
+            Some(total) => {
+                if end > total {
+                    return Err(Error::InvalidRange);
+                }
+                Some(total)
+            }
+            None => is_last.then_some(end),
+        };
+
+        let is_last = total == Some(end);
+        if !is_last {
+            if length == 0 {
+                return Err(Error::EmptyChunk);
+            }
+            if !length.is_multiple_of(RESUMABLE_CHUNK_ALIGNMENT) {
+                return Err(Error::UnalignedChunk { len: length });
+            }
+        }
+
+        let total_str = total.map_or_else(|| "*".to_owned(), |total| total.to_string());
+        let content_range = if length == 0 {
+            format!("bytes */{total_str}")
+        } else {
+            format!("bytes {start}-{}/{total_str}", end - 1)
+        };
+
+        let mut req_builder = http::Request::builder()
+            .header(http::header::CONTENT_LENGTH, length)
+            .header(http::header::CONTENT_RANGE, content_range);
+
+        if is_last
+            && let Some(hashes) = &self.hashes
+            && let Some(value) = hashes.to_header_value()?
+        {
+            req_builder = req_builder.header("x-goog-hash", value);
+        }
+
+        let req = req_builder
+            .method("PUT")
+            .uri(self.session.clone())
+            .body(content)?;
+
+        self.total = total;
+        Ok(req)
+    }
+
+    /// Creates the request used to query how many bytes GCS has persisted,
+    /// which should be sent after an [`ResumableUpload::append`] failed
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/performing-resumable-uploads#status-check)
+    pub fn status(&self) -> Result<http::Request<()>, Error> {
+        let total = self
+            .total
+            .map_or_else(|| "*".to_owned(), |total| total.to_string());
+
+        Ok(http::Request::builder()
+            .method("PUT")
+            .uri(self.session.clone())
+            .header(http::header::CONTENT_LENGTH, 0u64)
+            .header(http::header::CONTENT_RANGE, format!("bytes */{total}"))
+            .body(())?)
+    }
+
+    /// Updates the state of the upload with the response to either an
+    /// [`ResumableUpload::append`] or [`ResumableUpload::status`] request,
+    /// returning the object's metadata if the upload is complete. Fails with
+    /// [`Error::ExpiredSession`] if the upload needs to be restarted from the
+    /// beginning with a new session.
+    pub fn on_response(
+        &mut self,
+        response: ResumableInsertResponse,
+    ) -> Result<Option<Box<Metadata>>, Error> {
+        match response.metadata {
+            ResumableInsertResponseMetadata::PartialSize(persisted) => {
+                if self.total.is_some_and(|total| persisted > total) {
+                    return Err(Error::InvalidRange);
+                }
+
+                self.persisted = persisted;
+                Ok(None)
+            }
+            ResumableInsertResponseMetadata::Complete(metadata) => {
+                self.complete = true;
+                if let Some(total) = metadata.size.or(self.total) {
+                    self.total = Some(total);
+                    self.persisted = total;
+                }
+                Ok(Some(metadata))
+            }
+            ResumableInsertResponseMetadata::SessionExpired => Err(Error::ExpiredSession),
+        }
+    }
+}
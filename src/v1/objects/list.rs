@@ -54,6 +54,10 @@ pub struct ListOptional<'a> {
     /// The default is false. For more information, see Object Versioning.
     #[serde(skip_serializing_if = "crate::util::if_false")]
     pub versions: bool,
+    /// If true, only lists soft-deleted objects, including every soft-deleted
+    /// version of an object. Can't be combined with `versions`.
+    #[serde(skip_serializing_if = "crate::util::if_false")]
+    pub soft_deleted: bool,
 }
 
 pub struct ListResponse {
@@ -1,9 +1,160 @@
+use super::{AccessControl, EncryptionKey, Metadata, ObjectContexts, ObjectRetention, Timestamp};
 use crate::{
     common::{Conditionals, StandardQueryParameters},
     error::Error,
     response::ApiResponse,
     types::ObjectIdentifier,
 };
+use std::collections::BTreeMap;
+
+/// A change to a single field in a [`MetadataPatch`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Patch<T> {
+    /// The field is left as is
+    #[default]
+    Unchanged,
+    /// The field is removed from the object
+    Clear,
+    /// The field is set to the value
+    Set(T),
+}
+
+impl<T> Patch<T> {
+    /// Whether the field is left as is
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+}
+
+impl<T> From<T> for Patch<T> {
+    fn from(value: T) -> Self {
+        Self::Set(value)
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Patch<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Set(value) => value.serialize(serializer),
+            // Unchanged fields are skipped by their containers, so this is
+            // only reached for fields that are cleared
+            Self::Unchanged | Self::Clear => serializer.serialize_none(),
+        }
+    }
+}
+
+/// A [JSON merge patch](https://cloud.google.com/storage/docs/json_api/v1/how-tos/performance#patch)
+/// of an object's writable metadata. Unlike [`Metadata`], where fields that
+/// aren't set are left as is, fields can also be cleared by sending `null`.
+#[derive(Default, Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataPatch {
+    /// `Content-Type` of the object data.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub content_type: Patch<String>,
+    /// `Content-Disposition` of the object data.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub content_disposition: Patch<String>,
+    /// `Content-Encoding` of the object data.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub content_encoding: Patch<String>,
+    /// `Content-Language` of the object data.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub content_language: Patch<String>,
+    /// `Cache-Control` directive for the object data.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub cache_control: Patch<String>,
+    /// A user-specified timestamp for the object. Once set on an object, it
+    /// can only be changed to a later time.
+    #[serde(
+        skip_serializing_if = "Patch::is_unchanged",
+        serialize_with = "to_timestamp_rfc3339_patch"
+    )]
+    pub custom_time: Patch<Timestamp>,
+    /// Whether or not the object is subject to a temporary hold.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub temporary_hold: Patch<bool>,
+    /// Whether or not the object is subject to an event-based hold.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub event_based_hold: Patch<bool>,
+    /// The object's own retention configuration.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub retention: Patch<ObjectRetention>,
+    /// Access controls on the object.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub acl: Patch<Vec<AccessControl>>,
+    /// Contexts attached to the object.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub contexts: Patch<ObjectContexts>,
+    /// User-provided metadata. Setting it only changes the keys in the map
+    /// that aren't [`Patch::Unchanged`], leaving other keys as is, while
+    /// clearing it removes every key.
+    #[serde(
+        skip_serializing_if = "Patch::is_unchanged",
+        serialize_with = "to_metadata_patch"
+    )]
+    pub metadata: Patch<BTreeMap<String, Patch<String>>>,
+}
+
+impl MetadataPatch {
+    /// Sets a single user-provided metadata key, leaving other keys as is
+    pub fn set_metadata(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.metadata_entries()
+            .insert(key.into(), Patch::Set(value.into()));
+    }
+
+    /// Removes a single user-provided metadata key, leaving other keys as is
+    pub fn clear_metadata(&mut self, key: impl Into<String>) {
+        self.metadata_entries().insert(key.into(), Patch::Clear);
+    }
+
+    fn metadata_entries(&mut self) -> &mut BTreeMap<String, Patch<String>> {
+        if !matches!(self.metadata, Patch::Set(_)) {
+            self.metadata = Patch::Set(BTreeMap::new());
+        }
+
+        match &mut self.metadata {
+            Patch::Set(entries) => entries,
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn to_timestamp_rfc3339_patch<S>(ts: &Patch<Timestamp>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match ts {
+        Patch::Set(ts) => super::to_timestamp_rfc3339_opt(&Some(*ts), serializer),
+        Patch::Unchanged | Patch::Clear => serializer.serialize_none(),
+    }
+}
+
+fn to_metadata_patch<S>(
+    metadata: &Patch<BTreeMap<String, Patch<String>>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match metadata {
+        Patch::Set(entries) => {
+            serializer.collect_map(entries.iter().filter(|(_, value)| !value.is_unchanged()))
+        }
+        Patch::Unchanged | Patch::Clear => serializer.serialize_none(),
+    }
+}
+
+/// The body of an [`Object::patch`](super::Object::patch) request, either a
+/// [`Metadata`], where only the fields that are set are changed, or a
+/// [`MetadataPatch`], which can also clear fields
+pub trait PatchMetadata: serde::Serialize {}
+
+impl PatchMetadata for Metadata {}
+impl PatchMetadata for MetadataPatch {}
 
 #[derive(Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -15,10 +166,14 @@ pub struct PatchObjectOptional<'a> {
     /// The project to be billed for this request. Required for Requester Pays buckets.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_project: Option<&'a str>,
+    /// The customer-supplied key the object is encrypted with, sent as the
+    /// `x-goog-encryption-*` headers.
+    #[serde(skip)]
+    pub encryption_key: Option<&'a EncryptionKey>,
 }
 
 pub struct PatchObjectResponse {
-    pub metadata: super::Metadata,
+    pub metadata: Metadata,
 }
 
 impl ApiResponse<&[u8]> for PatchObjectResponse {}
@@ -32,36 +187,43 @@ where
 
     fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
         let (_parts, body) = response.into_parts();
-        let metadata: super::Metadata = serde_json::from_slice(body.as_ref())?;
+        let metadata: Metadata = serde_json::from_slice(body.as_ref())?;
         Ok(Self { metadata })
     }
 }
 
 impl super::Object {
-    /// Updates a data blob's associated metadata.
+    /// Updates a data blob's associated metadata, either with a [`Metadata`]
+    /// or a [`MetadataPatch`] if fields need to be cleared.
     ///
     /// Required IAM Permissions: `storage.objects.get`, `storage.objects.update`
     ///
     /// [Complete API documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/patch)
-    pub fn patch<'a, OID>(
+    pub fn patch<'a, OID, M>(
         &self,
         id: &OID,
-        metadata: &super::Metadata,
+        metadata: &M,
         optional: Option<PatchObjectOptional<'_>>,
     ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error>
     where
         OID: ObjectIdentifier<'a> + ?Sized,
+        M: PatchMetadata,
     {
         let mut uri = crate::__make_obj_url!("https://{}/storage/v1/b/{}/o/{}", self.authority, id);
 
         let query = optional.unwrap_or_default();
+        let encryption_key = query.encryption_key;
         let query_params = serde_urlencoded::to_string(query)?;
         if !query_params.is_empty() {
             uri.push('?');
             uri.push_str(&query_params);
         }
 
-        let req_builder = http::Request::builder();
+        let mut req_builder = http::Request::builder();
+
+        if let Some(key) = encryption_key {
+            req_builder = key.apply(req_builder);
+        }
 
         let md = serde_json::to_vec(&metadata)?;
         let len = md.len();
@@ -0,0 +1,218 @@
+use crate::{
+    common::{Conditionals, Projection, StandardQueryParameters},
+    error::Error,
+    response::ApiResponse,
+    types::{BucketName, ObjectIdentifier},
+};
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreObjectOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// If true, copies the source object's ACL, otherwise uses the bucket's
+    /// default object ACL.
+    #[serde(skip_serializing_if = "crate::util::if_false")]
+    pub copy_source_acl: bool,
+    #[serde(flatten)]
+    pub conditionals: Conditionals,
+    /// Set of properties to return. Defaults to `full`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Projection>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+/// The response from a [`restore`](#method.restore) request is the object
+/// [metadata](https://cloud.google.com/storage/docs/json_api/v1/objects#resource)
+/// of the restored object.
+pub struct RestoreObjectResponse {
+    pub metadata: super::Metadata,
+}
+
+impl ApiResponse<&[u8]> for RestoreObjectResponse {}
+impl ApiResponse<bytes::Bytes> for RestoreObjectResponse {}
+
+impl<B> TryFrom<http::Response<B>> for RestoreObjectResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let metadata: super::Metadata = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { metadata })
+    }
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkRestoreObjectOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+/// The soft-deleted objects to restore in a [`bulk_restore`](#method.bulk_restore)
+/// request
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkRestoreOptions<'a> {
+    /// If false, an object is only restored if there isn't a live version of
+    /// it. If true, any live version is replaced by the restored one.
+    pub allow_overwrite: bool,
+    /// If true, copies the source object's ACL, otherwise uses the bucket's
+    /// default object ACL.
+    #[serde(skip_serializing_if = "crate::util::if_false")]
+    pub copy_source_acl: bool,
+    /// Restores only the objects whose names match one of these
+    /// [globs](https://cloud.google.com/storage/docs/json_api/v1/objects/list#list-objects-and-prefixes-using-glob),
+    /// or every soft-deleted object if empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub match_globs: Vec<&'a str>,
+    /// Restores only the objects that were soft-deleted after this time.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::to_timestamp_rfc3339_opt"
+    )]
+    pub soft_deleted_after_time: Option<super::Timestamp>,
+    /// Restores only the objects that were soft-deleted before this time.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "super::to_timestamp_rfc3339_opt"
+    )]
+    pub soft_deleted_before_time: Option<super::Timestamp>,
+}
+
+/// The error of a failed [`Operation`]
+#[derive(Clone, Debug, Deserialize)]
+pub struct OperationError {
+    /// The status code of the error
+    pub code: i32,
+    /// The developer-facing error message
+    pub message: Option<String>,
+}
+
+/// A [long-running operation](https://cloud.google.com/storage/docs/json_api/v1/operations),
+/// which can be polled until it is done
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    /// The name of the operation, eg. `projects/_/buckets/bucket/operations/id`
+    pub name: String,
+    /// Whether the operation has completed, either successfully or with an
+    /// [`Operation::error`]
+    #[serde(default)]
+    pub done: bool,
+    /// Progress information about the operation, which depends on its type
+    pub metadata: Option<serde_json::Value>,
+    /// The error of the operation, if it failed
+    pub error: Option<OperationError>,
+    /// The link to the operation
+    pub self_link: Option<String>,
+}
+
+/// The response from a [`bulk_restore`](#method.bulk_restore) request, the
+/// long-running operation performing the restore
+pub struct BulkRestoreResponse {
+    pub operation: Operation,
+}
+
+impl ApiResponse<&[u8]> for BulkRestoreResponse {}
+impl ApiResponse<bytes::Bytes> for BulkRestoreResponse {}
+
+impl<B> TryFrom<http::Response<B>> for BulkRestoreResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let operation: Operation = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { operation })
+    }
+}
+
+impl super::Object {
+    /// Restores a soft-deleted object. The `generation` of the soft-deleted
+    /// object is required, and can be found by listing objects with
+    /// [`ListOptional::soft_deleted`](super::ListOptional::soft_deleted).
+    ///
+    /// Required IAM Permissions: `storage.objects.restore`, `storage.objects.create`,
+    /// `storage.objects.delete`*, `storage.objects.getIamPolicy`*
+    ///
+    /// [Complete API documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/restore)
+    pub fn restore<'a, OID>(
+        &self,
+        id: &OID,
+        generation: i64,
+        optional: Option<RestoreObjectOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let mut uri = crate::__make_obj_url!(
+            "https://{}/storage/v1/b/{}/o/{}/restore",
+            self.authority,
+            id
+        );
+
+        uri.push_str("?generation=");
+        uri.push_str(&generation.to_string());
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('&');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("POST").uri(uri).body(std::io::empty())?)
+    }
+
+    /// Starts a long-running operation that restores many soft-deleted
+    /// objects in a bucket at once.
+    ///
+    /// Required IAM Permissions: `storage.objects.restore`, `storage.objects.create`,
+    /// `storage.objects.delete`*, `storage.objects.list`
+    ///
+    /// [Complete API documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/bulkRestore)
+    pub fn bulk_restore(
+        &self,
+        bucket: &BucketName<'_>,
+        options: &BulkRestoreOptions<'_>,
+        optional: Option<BulkRestoreObjectOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error> {
+        let mut uri = format!(
+            "https://{}/storage/v1/b/{}/o/bulkRestore",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(bucket.as_ref(), crate::util::PATH_ENCODE_SET),
+        );
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let body = serde_json::to_vec(options)?;
+        let len = body.len();
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder
+            .method("POST")
+            .header("content-type", "application/json")
+            .header("content-length", len)
+            .uri(uri)
+            .body(std::io::Cursor::new(body))?)
+    }
+}
@@ -1,3 +1,4 @@
+use super::EncryptionKey;
 use crate::{
     common::{Conditionals, Projection, StandardQueryParameters},
     error::Error,
@@ -72,6 +73,14 @@ pub struct RewriteObjectOptional<'a> {
     /// to the latest version, the default).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_generation: Option<i64>,
+    /// The customer-supplied key to encrypt the destination object with,
+    /// sent as the `x-goog-encryption-*` headers.
+    #[serde(skip)]
+    pub encryption_key: Option<&'a EncryptionKey>,
+    /// The customer-supplied key the source object is encrypted with, sent
+    /// as the `x-goog-copy-source-encryption-*` headers.
+    #[serde(skip)]
+    pub source_encryption_key: Option<&'a EncryptionKey>,
 }
 
 #[derive(Deserialize)]
@@ -148,6 +157,8 @@ impl super::Object {
         );
 
         let query = optional.unwrap_or_default();
+        let (encryption_key, source_encryption_key) =
+            (query.encryption_key, query.source_encryption_key);
         let query_params = serde_urlencoded::to_string(query)?;
         if !query_params.is_empty() || rewrite_token.is_some() {
             uri.push('?');
@@ -168,6 +179,14 @@ impl super::Object {
 
         let mut req_builder = http::Request::builder();
 
+        if let Some(key) = encryption_key {
+            req_builder = key.apply(req_builder);
+        }
+
+        if let Some(key) = source_encryption_key {
+            req_builder = key.apply_source(req_builder);
+        }
+
         let body = match metadata {
             Some(metadata) => {
                 let md = serde_json::to_vec(&metadata)?;
@@ -0,0 +1,91 @@
+use crate::{
+    common::{Conditionals, PredefinedAcl, Projection, StandardQueryParameters},
+    error::Error,
+    response::ApiResponse,
+    types::ObjectIdentifier,
+};
+
+/// Optional parameters when updating an object.
+/// See [here](https://cloud.google.com/storage/docs/json_api/v1/objects/update#parameters)
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateObjectOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// If present, selects a specific revision of this object
+    /// (as opposed to the latest version, the default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation: Option<i64>,
+    #[serde(flatten)]
+    pub conditionals: Conditionals,
+    /// Apply a predefined set of access controls to this object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predefined_acl: Option<PredefinedAcl>,
+    /// Set of properties to return. Defaults to `full`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<Projection>,
+    /// The project to be billed for this request. Required for Requester Pays buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub struct UpdateObjectResponse {
+    pub metadata: super::Metadata,
+}
+
+impl ApiResponse<&[u8]> for UpdateObjectResponse {}
+impl ApiResponse<bytes::Bytes> for UpdateObjectResponse {}
+
+impl<B> TryFrom<http::Response<B>> for UpdateObjectResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+        let metadata: super::Metadata = serde_json::from_slice(body.as_ref())?;
+        Ok(Self { metadata })
+    }
+}
+
+impl super::Object {
+    /// Replaces a data blob's associated metadata. Unlike [`patch`](#method.patch),
+    /// any writable metadata that is not specified in `metadata`, such as custom
+    /// metadata keys or content headers, is removed from the object.
+    ///
+    /// Required IAM Permissions: `storage.objects.get`, `storage.objects.update`
+    ///
+    /// [Complete API documentation](https://cloud.google.com/storage/docs/json_api/v1/objects/update)
+    pub fn update<'a, OID>(
+        &self,
+        id: &OID,
+        metadata: &super::Metadata,
+        optional: Option<UpdateObjectOptional<'_>>,
+    ) -> Result<http::Request<std::io::Cursor<Vec<u8>>>, Error>
+    where
+        OID: ObjectIdentifier<'a> + ?Sized,
+    {
+        let mut uri = crate::__make_obj_url!("https://{}/storage/v1/b/{}/o/{}", self.authority, id);
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        let md = serde_json::to_vec(&metadata)?;
+        let len = md.len();
+        let md = std::io::Cursor::new(md);
+
+        Ok(req_builder
+            .method("PUT")
+            .header("content-type", "application/json")
+            .header("content-length", len)
+            .uri(uri)
+            .body(md)?)
+    }
+}
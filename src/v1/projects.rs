@@ -0,0 +1,101 @@
+//! Types and APIs for interacting with GCS [Projects](https://cloud.google.com/storage/docs/json_api/v1/projects)
+
+use crate::{common::StandardQueryParameters, error::Error, response::ApiResponse};
+use http::uri::Authority;
+
+/// Helper struct used to collate all of the operations available for
+/// [Projects](https://cloud.google.com/storage/docs/json_api/v1/projects)
+/// Additionally, it can also be used to specify a custom authority.
+#[derive(Clone, Debug)]
+pub struct Project {
+    authority: Authority,
+}
+
+impl Project {
+    /// Supplies a custom HTTP authority, allowing a GCS host other than the
+    /// standard `storage.googleapis.com` to be used
+    pub fn with_authority(authority: Authority) -> Self {
+        Self { authority }
+    }
+}
+
+impl Default for Project {
+    /// Defaults to the standard GCS location `storage.googleapis.com`
+    fn default() -> Self {
+        Self {
+            authority: Authority::from_static("storage.googleapis.com"),
+        }
+    }
+}
+
+/// Optional parameters when getting a project's service account
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetServiceAccountOptional<'a> {
+    #[serde(flatten)]
+    pub standard_params: StandardQueryParameters<'a>,
+    /// The project to be billed for this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_project: Option<&'a str>,
+}
+
+pub struct GetServiceAccountResponse {
+    /// The email address of the project's Cloud Storage service agent, which
+    /// needs to be granted access to eg. KMS keys or Pub/Sub topics that GCS
+    /// uses on behalf of the project.
+    pub email_address: String,
+}
+
+impl ApiResponse<&[u8]> for GetServiceAccountResponse {}
+impl ApiResponse<bytes::Bytes> for GetServiceAccountResponse {}
+
+impl<B> TryFrom<http::Response<B>> for GetServiceAccountResponse
+where
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn try_from(response: http::Response<B>) -> Result<Self, Self::Error> {
+        let (_parts, body) = response.into_parts();
+
+        // Unlike every other resource, this one uses snake case
+        #[derive(Deserialize)]
+        struct RawServiceAccount {
+            email_address: String,
+        }
+
+        let res: RawServiceAccount = serde_json::from_slice(body.as_ref())?;
+
+        Ok(Self {
+            email_address: res.email_address,
+        })
+    }
+}
+
+impl Project {
+    /// Get the email address of the project's Cloud Storage service account.
+    ///
+    /// [Complete API Documentation](https://cloud.google.com/storage/docs/json_api/v1/projects/serviceAccount/get)
+    pub fn get_service_account(
+        &self,
+        project: &str,
+        optional: Option<GetServiceAccountOptional<'_>>,
+    ) -> Result<http::Request<std::io::Empty>, Error> {
+        let mut uri = format!(
+            "https://{}/storage/v1/projects/{}/serviceAccount",
+            self.authority.as_str(),
+            percent_encoding::percent_encode(project.as_bytes(), crate::util::PATH_ENCODE_SET),
+        );
+
+        let query = optional.unwrap_or_default();
+        let query_params = serde_urlencoded::to_string(query)?;
+        if !query_params.is_empty() {
+            uri.push('?');
+            uri.push_str(&query_params);
+        }
+
+        let req_builder = http::Request::builder();
+
+        Ok(req_builder.method("GET").uri(uri).body(std::io::empty())?)
+    }
+}
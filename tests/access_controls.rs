@@ -0,0 +1,152 @@
+use tame_gcs::{
+    BucketName,
+    bucket_access_controls::{BucketAcl, ListBucketAccessControlsResponse},
+    buckets::GetBucketResponse,
+    common::{AccessControl, MetagenerationConditionals, Role},
+    default_object_access_controls::{DefaultObjectAcl, ListDefaultObjectAccessControlsOptional},
+    object_access_controls::GetObjectAccessControlResponse,
+    projects::{GetServiceAccountResponse, Project},
+};
+
+mod util;
+
+#[test]
+fn inserts_bucket_acl() {
+    let acl = AccessControl::new("group-uploaders@example.com", Role::Writer);
+
+    let insert_req = BucketAcl::default()
+        .insert(&BucketName::non_validated("bucket"), &acl, None)
+        .unwrap();
+
+    let req_body = r#"{"entity":"group-uploaders@example.com","role":"WRITER"}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/acl?prettyPrint=false")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(insert_req, expected);
+}
+
+#[test]
+fn lists_default_object_acl() {
+    let list_req = DefaultObjectAcl::default()
+        .list(
+            &BucketName::non_validated("bucket"),
+            Some(ListDefaultObjectAccessControlsOptional {
+                conditionals: MetagenerationConditionals {
+                    if_metageneration_match: Some(3),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/defaultObjectAcl?prettyPrint=false&ifMetagenerationMatch=3")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&list_req, &expected);
+}
+
+#[test]
+fn deletes_default_object_acl() {
+    let delete_req = DefaultObjectAcl::default()
+        .delete(&BucketName::non_validated("bucket"), "allUsers", None)
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::DELETE)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/defaultObjectAcl/allUsers?prettyPrint=false")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&delete_req, &expected);
+}
+
+#[test]
+fn gets_service_account() {
+    let get_req = Project::default()
+        .get_service_account("my-project", None)
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/projects/my-project/serviceAccount?prettyPrint=false")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&get_req, &expected);
+
+    let body = r#"{
+        "kind": "storage#serviceAccount",
+        "email_address": "service-123456789@gs-project-accounts.iam.gserviceaccount.com"
+    }"#;
+
+    let response =
+        GetServiceAccountResponse::try_from(http::Response::new(body.as_bytes())).unwrap();
+    assert_eq!(
+        response.email_address,
+        "service-123456789@gs-project-accounts.iam.gserviceaccount.com"
+    );
+}
+
+#[test]
+fn parses_shared_entity_model() {
+    let bucket_acl = r#"{
+        "kind": "storage#bucketAccessControl",
+        "id": "bucket/project-owners-123456789",
+        "bucket": "bucket",
+        "entity": "project-owners-123456789",
+        "role": "OWNER",
+        "projectTeam": { "projectNumber": "123456789", "team": "owners" },
+        "etag": "CAE="
+    }"#;
+
+    let list = format!(r#"{{"kind": "storage#bucketAccessControls", "items": [{bucket_acl}]}}"#);
+    let items = ListBucketAccessControlsResponse::try_from(http::Response::new(list.as_bytes()))
+        .unwrap()
+        .items;
+    assert_eq!(items[0].role, Some(Role::Owner));
+    assert_eq!(
+        items[0].project_team.as_ref().unwrap().team.as_deref(),
+        Some("owners")
+    );
+
+    let object_acl = r#"{
+        "kind": "storage#objectAccessControl",
+        "bucket": "bucket",
+        "object": "object",
+        "generation": "12",
+        "entity": "allUsers",
+        "role": "READER"
+    }"#;
+
+    let acl = GetObjectAccessControlResponse::try_from(http::Response::new(object_acl.as_bytes()))
+        .unwrap()
+        .acl;
+    assert_eq!(acl.generation, Some(12));
+    assert_eq!(acl.role, Some(Role::Reader));
+
+    let bucket = format!(
+        r#"{{"name": "bucket", "acl": [{bucket_acl}], "defaultObjectAcl": [{object_acl}], "owner": {{ "entity": "project-owners-123456789" }}}}"#
+    );
+    let md = GetBucketResponse::try_from(http::Response::new(bucket.as_bytes()))
+        .unwrap()
+        .metadata;
+    assert_eq!(md.acl.unwrap().len(), 1);
+    assert_eq!(
+        md.default_object_acl.unwrap()[0].entity.as_deref(),
+        Some("allUsers")
+    );
+    assert_eq!(
+        md.owner.unwrap().entity.as_deref(),
+        Some("project-owners-123456789")
+    );
+}
@@ -0,0 +1,356 @@
+use http::uri::Authority;
+use tame_gcs::{
+    BucketName,
+    buckets::{self, Action, Bucket, BucketMetadata, Lifecycle, LifecycleRule},
+    common::{MetagenerationConditionals, PredefinedBucketAcl, StorageClass},
+    objects::Metadata,
+};
+
+mod util;
+
+#[test]
+fn vanilla_get() {
+    let get_req = Bucket::default()
+        .get(&BucketName::non_validated("bucket"), None)
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket?prettyPrint=false")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&get_req, &expected);
+}
+
+#[test]
+fn list_using_custom_authority() {
+    let list_req = Bucket::with_authority(Authority::from_static("0.0.0.0:4443"))
+        .list(
+            "my-project",
+            Some(buckets::ListBucketsOptional {
+                prefix: Some("build-"),
+                max_results: Some(10),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://0.0.0.0:4443/storage/v1/b?project=my-project&prettyPrint=false&maxResults=10&prefix=build-")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&list_req, &expected);
+}
+
+#[test]
+fn inserts() {
+    let md = BucketMetadata {
+        name: Some("new-bucket".to_owned()),
+        location: Some("EU".to_owned()),
+        storage_class: Some(StorageClass::Standard),
+        lifecycle: Some(Lifecycle {
+            rule: vec![LifecycleRule {
+                action: Action::SetStorageClass {
+                    storage_class: StorageClass::Coldline,
+                },
+                condition: buckets::Condition {
+                    age: Some(30),
+                    created_before: Some(time::macros::date!(2024 - 01 - 02)),
+                    ..Default::default()
+                },
+            }],
+        }),
+        ..Default::default()
+    };
+
+    let insert_req = Bucket::default()
+        .insert(
+            "my-project",
+            &md,
+            Some(buckets::InsertBucketOptional {
+                predefined_acl: Some(PredefinedBucketAcl::ProjectPrivate),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let req_body = r#"{"name":"new-bucket","location":"EU","storageClass":"STANDARD","lifecycle":{"rule":[{"action":{"type":"SetStorageClass","storageClass":"COLDLINE"},"condition":{"age":30,"createdBefore":"2024-01-02"}}]}}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/storage/v1/b?project=my-project&prettyPrint=false&predefinedAcl=projectPrivate")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(insert_req, expected);
+}
+
+#[test]
+fn insert_requires_name() {
+    assert!(matches!(
+        Bucket::default().insert("my-project", &BucketMetadata::default(), None),
+        Err(tame_gcs::Error::MissingMetadata("name"))
+    ));
+}
+
+#[test]
+fn delete_conditional() {
+    let delete_req = Bucket::default()
+        .delete(
+            &BucketName::non_validated("bucket"),
+            Some(buckets::DeleteBucketOptional {
+                conditionals: MetagenerationConditionals {
+                    if_metageneration_match: Some(4),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::DELETE)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket?prettyPrint=false&ifMetagenerationMatch=4")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&delete_req, &expected);
+}
+
+#[test]
+fn parses_get_response() {
+    let body = r#"{
+        "kind": "storage#bucket",
+        "selfLink": "https://www.googleapis.com/storage/v1/b/bucket",
+        "id": "bucket",
+        "name": "bucket",
+        "projectNumber": "123456789",
+        "metageneration": "7",
+        "location": "EUROPE-NORTH1",
+        "storageClass": "STANDARD",
+        "etag": "CAc=",
+        "timeCreated": "2020-06-09T13:15:11.706Z",
+        "updated": "2021-02-18T13:32:27.315Z",
+        "labels": {
+          "team": "build"
+        },
+        "versioning": {
+          "enabled": true
+        },
+        "lifecycle": {
+          "rule": [
+            {
+              "action": { "type": "Delete" },
+              "condition": { "numNewerVersions": 3, "isLive": false }
+            },
+            {
+              "action": { "type": "AbortIncompleteMultipartUpload" },
+              "condition": { "age": 7 }
+            },
+            {
+              "action": { "type": "SetStorageClass", "storageClass": "ARCHIVE" },
+              "condition": { "age": 365, "matchesStorageClass": ["STANDARD", "NEARLINE"], "matchesPrefix": ["logs/"] }
+            }
+          ]
+        },
+        "cors": [
+          {
+            "origin": ["https://example.com"],
+            "method": ["GET", "HEAD"],
+            "responseHeader": ["Content-Type"],
+            "maxAgeSeconds": 3600
+          }
+        ],
+        "retentionPolicy": {
+          "retentionPeriod": "86400",
+          "effectiveTime": "2021-02-18T13:32:27.315Z"
+        },
+        "iamConfiguration": {
+          "bucketPolicyOnly": { "enabled": true, "lockedTime": "2021-05-19T13:32:27.315Z" },
+          "uniformBucketLevelAccess": { "enabled": true, "lockedTime": "2021-05-19T13:32:27.315Z" },
+          "publicAccessPrevention": "enforced"
+        },
+        "softDeletePolicy": {
+          "retentionDurationSeconds": "604800",
+          "effectiveTime": "2024-03-01T08:00:00.000Z"
+        },
+        "autoclass": {
+          "enabled": false,
+          "toggleTime": "2024-03-01T08:00:00.000Z"
+        },
+        "hierarchicalNamespace": {
+          "enabled": false
+        },
+        "locationType": "region"
+      }"#;
+
+    let response = http::Response::new(body);
+    let get_response = buckets::GetBucketResponse::try_from(response).expect("parsed get response");
+    let md = get_response.metadata;
+
+    assert_eq!(md.project_number, Some(123456789));
+    assert_eq!(md.metageneration, Some(7));
+    assert_eq!(md.location_type.as_deref(), Some("region"));
+    assert!(md.versioning.unwrap().enabled);
+
+    let rules = md.lifecycle.unwrap().rule;
+    assert_eq!(rules.len(), 3);
+    assert_eq!(rules[0].action, Action::Delete);
+    assert_eq!(rules[0].condition.num_newer_versions, Some(3));
+    assert_eq!(rules[1].action, Action::AbortIncompleteMultipartUpload);
+    assert_eq!(
+        rules[2].action,
+        Action::SetStorageClass {
+            storage_class: StorageClass::Archive
+        }
+    );
+
+    assert_eq!(md.cors.unwrap()[0].max_age_seconds, Some(3600));
+    assert_eq!(md.retention_policy.unwrap().retention_period, Some(86400));
+    assert_eq!(
+        md.soft_delete_policy.unwrap().retention_duration_seconds,
+        Some(604800)
+    );
+    assert!(
+        md.iam_configuration
+            .unwrap()
+            .uniform_bucket_level_access
+            .unwrap()
+            .enabled
+    );
+}
+
+#[test]
+fn builds_lifecycle_rules() {
+    let lifecycle = Lifecycle::default()
+        .with_rule(
+            LifecycleRule::set_storage_class(StorageClass::Nearline)
+                .age(30)
+                .matches_storage_class(StorageClass::Standard),
+        )
+        .with_rule(LifecycleRule::delete().is_live(false).num_newer_versions(2))
+        .with_rule(LifecycleRule::abort_incomplete_multipart_upload().age(1));
+
+    assert_eq!(
+        serde_json::to_string(&lifecycle).unwrap(),
+        r#"{"rule":[{"action":{"type":"SetStorageClass","storageClass":"NEARLINE"},"condition":{"age":30,"matchesStorageClass":["STANDARD"]}},{"action":{"type":"Delete"},"condition":{"isLive":false,"numNewerVersions":2}},{"action":{"type":"AbortIncompleteMultipartUpload"},"condition":{"age":1}}]}"#
+    );
+}
+
+#[test]
+fn evaluates_lifecycle_precedence() {
+    let now = time::macros::datetime!(2024-06-01 12:00 UTC);
+
+    let lifecycle = Lifecycle::default()
+        .with_rule(LifecycleRule::set_storage_class(StorageClass::Nearline).age(30))
+        .with_rule(LifecycleRule::set_storage_class(StorageClass::Coldline).age(90))
+        .with_rule(
+            LifecycleRule::delete()
+                .matches_prefix("tmp/")
+                .created_before(time::macros::date!(2024 - 05 - 01)),
+        );
+
+    let object = |name: &str, created: time::OffsetDateTime| Metadata {
+        name: Some(name.to_owned()),
+        storage_class: Some(StorageClass::Standard),
+        time_created: Some(created),
+        ..Default::default()
+    };
+
+    // Too young for any rule
+    let young = object("data/young", time::macros::datetime!(2024-05-20 00:00 UTC));
+    assert_eq!(buckets::evaluate(&lifecycle, &young, now), None);
+
+    // Only old enough for nearline
+    let month = object("data/month", time::macros::datetime!(2024-04-20 00:00 UTC));
+    assert_eq!(
+        buckets::evaluate(&lifecycle, &month, now),
+        Some(&Action::SetStorageClass {
+            storage_class: StorageClass::Nearline
+        })
+    );
+
+    // Old enough for both, the cheapest storage class wins
+    let old = object("data/old", time::macros::datetime!(2024-01-01 00:00 UTC));
+    assert_eq!(
+        buckets::evaluate(&lifecycle, &old, now),
+        Some(&Action::SetStorageClass {
+            storage_class: StorageClass::Coldline
+        })
+    );
+
+    // Delete takes precedence over everything
+    let tmp = object("tmp/old", time::macros::datetime!(2024-01-01 00:00 UTC));
+    assert_eq!(
+        buckets::evaluate(&lifecycle, &tmp, now),
+        Some(&Action::Delete)
+    );
+}
+
+#[test]
+fn evaluates_lifecycle_versions() {
+    let now = time::macros::datetime!(2024-06-01 12:00 UTC);
+
+    let lifecycle = Lifecycle::default()
+        .with_rule(LifecycleRule::delete().is_live(false).num_newer_versions(2));
+
+    let version = |generation: i64, deleted: bool| Metadata {
+        name: Some("versioned".to_owned()),
+        generation: Some(generation),
+        time_created: Some(time::macros::datetime!(2024-01-01 00:00 UTC)),
+        time_deleted: deleted.then_some(time::macros::datetime!(2024-02-01 00:00 UTC)),
+        ..Default::default()
+    };
+
+    let listing = [
+        version(1, true),
+        version(2, true),
+        version(3, true),
+        version(4, false),
+    ];
+
+    assert_eq!(
+        buckets::evaluate_listing(&lifecycle, &listing, now),
+        vec![Some(&Action::Delete), Some(&Action::Delete), None, None]
+    );
+
+    // The number of newer versions can't be known from a single object
+    assert_eq!(buckets::evaluate(&lifecycle, &listing[0], now), None);
+}
+
+#[test]
+fn evaluates_lifecycle_times() {
+    let now = time::macros::datetime!(2024-06-01 12:00 UTC);
+
+    let lifecycle = Lifecycle::default()
+        .with_rule(LifecycleRule::delete().days_since_custom_time(7))
+        .with_rule(LifecycleRule::delete().days_since_noncurrent_time(30));
+
+    let object =
+        |custom: Option<time::OffsetDateTime>, deleted: Option<time::OffsetDateTime>| Metadata {
+            name: Some("artifact".to_owned()),
+            custom_time: custom,
+            time_deleted: deleted,
+            ..Default::default()
+        };
+
+    let fresh = object(Some(time::macros::datetime!(2024-05-30 00:00 UTC)), None);
+    assert_eq!(buckets::evaluate(&lifecycle, &fresh, now), None);
+
+    let stale = object(Some(time::macros::datetime!(2024-05-01 00:00 UTC)), None);
+    assert_eq!(
+        buckets::evaluate(&lifecycle, &stale, now),
+        Some(&Action::Delete)
+    );
+
+    let noncurrent = object(None, Some(time::macros::datetime!(2024-04-01 00:00 UTC)));
+    assert_eq!(
+        buckets::evaluate(&lifecycle, &noncurrent, now),
+        Some(&Action::Delete)
+    );
+}
@@ -0,0 +1,168 @@
+use tame_gcs::{
+    BucketName, ObjectId, ObjectName,
+    objects::{self, EncryptionKey, Object},
+    signing::{DigestAlgorithm, DigestCalulator},
+};
+
+mod util;
+
+/// Digest that doesn't actually hash anything, so the tests don't require
+/// the `signing` feature
+struct FakeDigest;
+
+impl DigestCalulator for FakeDigest {
+    fn digest(&self, _algorithm: DigestAlgorithm, data: &[u8], output_digest: &mut [u8]) {
+        output_digest.copy_from_slice(&data[..output_digest.len()]);
+        output_digest.reverse();
+    }
+}
+
+fn key() -> EncryptionKey {
+    let mut key = [0u8; 32];
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+
+    EncryptionKey::new(&key, &FakeDigest).unwrap()
+}
+
+/// The key header is marked as sensitive so that it isn't logged
+fn sensitive_key() -> http::HeaderValue {
+    let mut value = http::HeaderValue::from_static(KEY);
+    value.set_sensitive(true);
+    value
+}
+
+const KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+const KEY_SHA256: &str = "Hx4dHBsaGRgXFhUUExIREA8ODQwLCgkIBwYFBAMCAQA=";
+
+#[test]
+fn downloads_encrypted() {
+    let key = key();
+    assert_eq!(key.key_sha256(), KEY_SHA256);
+    assert!(!format!("{key:?}").contains(KEY));
+
+    let download_req = Object::default()
+        .download(
+            &(
+                &BucketName::non_validated("bucket"),
+                &ObjectName::non_validated("secret"),
+            ),
+            Some(objects::DownloadObjectOptional {
+                encryption_key: Some(&key),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/secret?alt=media&prettyPrint=false")
+        .header("x-goog-encryption-algorithm", "AES256")
+        .header("x-goog-encryption-key", sensitive_key())
+        .header("x-goog-encryption-key-sha256", KEY_SHA256)
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&download_req, &expected);
+}
+
+#[test]
+fn resumes_encrypted_download() {
+    let download = Object::default()
+        .resumable_download(&ObjectId::new("bucket", "secret").unwrap(), Some(3))
+        .with_encryption_key(key());
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/secret?alt=media&prettyPrint=false&generation=3&ifGenerationMatch=3")
+        .header("x-goog-encryption-algorithm", "AES256")
+        .header("x-goog-encryption-key", sensitive_key())
+        .header("x-goog-encryption-key-sha256", KEY_SHA256)
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&download.next_request().unwrap(), &expected);
+}
+
+#[test]
+fn inserts_encrypted() {
+    let key = key();
+
+    let insert_req = Object::default()
+        .insert_simple(
+            &(
+                &BucketName::non_validated("bucket"),
+                &ObjectName::non_validated("secret"),
+            ),
+            "great content",
+            13,
+            Some(objects::InsertObjectOptional {
+                encryption_key: Some(&key),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/upload/storage/v1/b/bucket/o?name=secret&uploadType=media&prettyPrint=false")
+        .header(http::header::CONTENT_TYPE, "application/octet-stream")
+        .header(http::header::CONTENT_LENGTH, 13)
+        .header("x-goog-encryption-algorithm", "AES256")
+        .header("x-goog-encryption-key", sensitive_key())
+        .header("x-goog-encryption-key-sha256", KEY_SHA256)
+        .body("great content")
+        .unwrap();
+
+    util::requests_eq(&insert_req, &expected);
+}
+
+#[test]
+fn rewrites_with_source_key() {
+    let key = key();
+
+    let rewrite_req = Object::default()
+        .rewrite(
+            &ObjectId::new("source", "secret").unwrap(),
+            &ObjectId::new("target", "plain").unwrap(),
+            None,
+            None,
+            Some(objects::RewriteObjectOptional {
+                source_encryption_key: Some(&key),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let headers = rewrite_req.headers();
+    assert_eq!(headers["x-goog-copy-source-encryption-algorithm"], "AES256");
+    assert_eq!(headers["x-goog-copy-source-encryption-key"], KEY);
+    assert_eq!(
+        headers["x-goog-copy-source-encryption-key-sha256"],
+        KEY_SHA256
+    );
+    assert!(headers.get("x-goog-encryption-key").is_none());
+}
+
+#[test]
+fn deserializes_customer_encryption() {
+    let body = format!(
+        r#"{{
+        "kind": "storage#object",
+        "name": "secret",
+        "bucket": "bucket",
+        "customerEncryption": {{
+            "encryptionAlgorithm": "AES256",
+            "keySha256": "{KEY_SHA256}"
+        }}
+    }}"#
+    );
+
+    let metadata: objects::Metadata = serde_json::from_str(&body).unwrap();
+    let encryption = metadata.customer_encryption.unwrap();
+
+    assert_eq!(encryption.encryption_algorithm, "AES256");
+    assert_eq!(encryption.key_sha256, key().key_sha256());
+    assert!(metadata.kms_key_name.is_none());
+}
@@ -0,0 +1,107 @@
+use tame_gcs::hmac_keys::{
+    CreateHmacKeyResponse, HmacKey, HmacKeyState, ListHmacKeysOptional, ListHmacKeysResponse,
+};
+
+mod util;
+
+#[test]
+fn creates() {
+    let create_req = HmacKey::default()
+        .create(
+            "my-project",
+            "uploader@my-project.iam.gserviceaccount.com",
+            None,
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/storage/v1/projects/my-project/hmacKeys?serviceAccountEmail=uploader@my-project.iam.gserviceaccount.com&prettyPrint=false")
+        .header(http::header::CONTENT_LENGTH, 0)
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&create_req, &expected);
+}
+
+#[test]
+fn lists() {
+    let list_req = HmacKey::default()
+        .list(
+            "my-project",
+            Some(ListHmacKeysOptional {
+                show_deleted_keys: Some(true),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/projects/my-project/hmacKeys?prettyPrint=false&showDeletedKeys=true")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&list_req, &expected);
+}
+
+#[test]
+fn deactivates() {
+    let update_req = HmacKey::default()
+        .update("my-project", "GOOG1EXAMPLE", HmacKeyState::Inactive, None)
+        .unwrap();
+
+    let req_body = r#"{"state":"INACTIVE"}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::PUT)
+        .uri("https://storage.googleapis.com/storage/v1/projects/my-project/hmacKeys/GOOG1EXAMPLE?prettyPrint=false")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(update_req, expected);
+}
+
+#[test]
+fn update_rejects_deleted() {
+    assert_eq!(
+        HmacKey::default()
+            .update("my-project", "GOOG1EXAMPLE", HmacKeyState::Deleted, None)
+            .unwrap_err(),
+        tame_gcs::Error::InvalidHmacKeyState
+    );
+}
+
+#[test]
+fn parses_create_response() {
+    let body = r#"{
+        "kind": "storage#hmacKey",
+        "metadata": {
+          "kind": "storage#hmacKeyMetadata",
+          "id": "my-project/GOOG1EXAMPLE",
+          "accessId": "GOOG1EXAMPLE",
+          "projectId": "my-project",
+          "serviceAccountEmail": "uploader@my-project.iam.gserviceaccount.com",
+          "state": "ACTIVE",
+          "timeCreated": "2021-02-18T13:32:27.315Z",
+          "updated": "2021-02-18T13:32:27.315Z",
+          "etag": "MTU5MTcwODUxMTcwNjc5Nw=="
+        },
+        "secret": "bGV0IG1lIGluIHBsZWFzZQ=="
+    }"#;
+
+    let response = http::Response::new(body.as_bytes());
+    let created = CreateHmacKeyResponse::try_from(response).unwrap();
+
+    assert_eq!(created.secret, "bGV0IG1lIGluIHBsZWFzZQ==");
+    assert_eq!(created.metadata.access_id, "GOOG1EXAMPLE");
+    assert_eq!(created.metadata.state, HmacKeyState::Active);
+    assert!(created.metadata.time_created.is_some());
+
+    let empty = http::Response::new(r#"{"kind": "storage#hmacKeysMetadata"}"#.as_bytes());
+    let listed = ListHmacKeysResponse::try_from(empty).unwrap();
+    assert!(listed.keys.is_empty());
+    assert!(listed.page_token.is_none());
+}
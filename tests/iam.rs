@@ -0,0 +1,156 @@
+use tame_gcs::{
+    BucketName, ObjectId,
+    buckets::{Bucket, GetBucketIamPolicyOptional},
+    iam::{CONDITIONAL_POLICY_VERSION, GetIamPolicyResponse, Policy, TestIamPermissionsResponse},
+    objects::{Object, ObjectIamOptional},
+};
+
+mod util;
+
+#[test]
+fn gets_bucket_policy() {
+    let get_req = Bucket::default()
+        .get_iam_policy(
+            &BucketName::non_validated("bucket"),
+            Some(GetBucketIamPolicyOptional {
+                options_requested_policy_version: Some(CONDITIONAL_POLICY_VERSION),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/iam?prettyPrint=false&optionsRequestedPolicyVersion=3")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&get_req, &expected);
+}
+
+#[test]
+fn sets_bucket_policy() {
+    let mut policy = Policy {
+        version: Some(1),
+        etag: Some("CAE=".to_owned()),
+        ..Default::default()
+    };
+    policy.add_member("roles/storage.objectViewer", "allUsers");
+
+    let set_req = Bucket::default()
+        .set_iam_policy(&BucketName::non_validated("bucket"), &policy, None)
+        .unwrap();
+
+    let req_body = r#"{"version":1,"bindings":[{"role":"roles/storage.objectViewer","members":["allUsers"]}],"etag":"CAE="}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::PUT)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/iam?prettyPrint=false")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(set_req, expected);
+}
+
+#[test]
+fn tests_object_permissions() {
+    let test_req = Object::default()
+        .test_iam_permissions(
+            &ObjectId::new("bucket", "object").unwrap(),
+            &["storage.objects.get", "storage.objects.delete"],
+            Some(ObjectIamOptional {
+                generation: Some(20),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/object/iam/testPermissions?permissions=storage.objects.get&permissions=storage.objects.delete&prettyPrint=false&generation=20")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&test_req, &expected);
+}
+
+#[test]
+fn rejects_empty_permissions() {
+    assert_eq!(
+        Object::default()
+            .test_iam_permissions(&ObjectId::new("bucket", "object").unwrap(), &[], None)
+            .unwrap_err(),
+        tame_gcs::Error::MissingPermissions
+    );
+    assert_eq!(
+        Bucket::default()
+            .test_iam_permissions(&BucketName::non_validated("bucket"), &[], None)
+            .unwrap_err(),
+        tame_gcs::Error::MissingPermissions
+    );
+}
+
+#[test]
+fn modifies_members() {
+    let body = r#"{
+        "kind": "storage#policy",
+        "resourceId": "projects/_/buckets/bucket",
+        "version": 3,
+        "bindings": [
+          {
+            "role": "roles/storage.legacyBucketOwner",
+            "members": ["projectOwner:my-project"]
+          },
+          {
+            "role": "roles/storage.objectViewer",
+            "members": ["user:someone@example.com"],
+            "condition": {
+              "title": "logs",
+              "expression": "resource.name.startsWith(\"projects/_/buckets/bucket/objects/logs/\")"
+            }
+          }
+        ],
+        "etag": "CAM="
+    }"#;
+
+    let response = http::Response::new(body.as_bytes());
+    let mut policy = GetIamPolicyResponse::try_from(response).unwrap().policy;
+
+    assert_eq!(
+        policy.resource_id.as_deref(),
+        Some("projects/_/buckets/bucket")
+    );
+    assert_eq!(
+        policy.bindings[1]
+            .condition
+            .as_ref()
+            .unwrap()
+            .title
+            .as_deref(),
+        Some("logs")
+    );
+
+    // The conditional binding is left alone, a new unconditional one is added
+    assert!(policy.add_member("roles/storage.objectViewer", "user:someone@example.com"));
+    assert!(!policy.add_member("roles/storage.objectViewer", "user:someone@example.com"));
+    assert_eq!(policy.bindings.len(), 3);
+
+    assert!(policy.remove_member("roles/storage.legacyBucketOwner", "projectOwner:my-project"));
+    assert!(!policy.remove_member("roles/storage.legacyBucketOwner", "projectOwner:my-project"));
+    assert_eq!(policy.bindings.len(), 2);
+    assert!(policy.bindings[0].condition.is_some());
+
+    assert_eq!(policy.etag.as_deref(), Some("CAM="));
+}
+
+#[test]
+fn parses_empty_permissions() {
+    let response =
+        http::Response::new(r#"{"kind": "storage#testIamPermissionsResponse"}"#.as_bytes());
+    let permissions = TestIamPermissionsResponse::try_from(response)
+        .unwrap()
+        .permissions;
+    assert!(permissions.is_empty());
+}
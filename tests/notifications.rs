@@ -0,0 +1,163 @@
+use tame_gcs::{
+    BucketName,
+    notifications::{
+        EventType, ListNotificationsResponse, Notification, NotificationConfig, PayloadFormat,
+        StorageEvent,
+    },
+};
+
+mod util;
+
+#[test]
+fn inserts() {
+    let mut config =
+        NotificationConfig::new("//pubsub.googleapis.com/projects/my-project/topics/uploads");
+    config.event_types = Some(vec![EventType::ObjectFinalize, EventType::ObjectArchive]);
+    config.object_name_prefix = Some("builds/".to_owned());
+    config.custom_attributes =
+        Some(std::iter::once(("team".to_owned(), "build".to_owned())).collect());
+
+    let insert_req = Notification::default()
+        .insert(&BucketName::non_validated("bucket"), &config, None)
+        .unwrap();
+
+    let req_body = r#"{"topic":"//pubsub.googleapis.com/projects/my-project/topics/uploads","eventTypes":["OBJECT_FINALIZE","OBJECT_ARCHIVE"],"customAttributes":{"team":"build"},"payloadFormat":"JSON_API_V1","objectNamePrefix":"builds/"}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/notificationConfigs?prettyPrint=false")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(insert_req, expected);
+}
+
+#[test]
+fn deletes() {
+    let delete_req = Notification::default()
+        .delete(&BucketName::non_validated("bucket"), "7", None)
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::DELETE)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/notificationConfigs/7?prettyPrint=false")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&delete_req, &expected);
+}
+
+#[test]
+fn parses_list_response() {
+    let body = r#"{
+        "kind": "storage#notifications",
+        "items": [
+          {
+            "kind": "storage#notification",
+            "id": "7",
+            "selfLink": "https://www.googleapis.com/storage/v1/b/bucket/notificationConfigs/7",
+            "topic": "//pubsub.googleapis.com/projects/my-project/topics/uploads",
+            "eventTypes": ["OBJECT_FINALIZE", "OBJECT_DELETE"],
+            "payloadFormat": "NONE",
+            "etag": "7"
+          }
+        ]
+    }"#;
+
+    let response = http::Response::new(body.as_bytes());
+    let items = ListNotificationsResponse::try_from(response).unwrap().items;
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].id.as_deref(), Some("7"));
+    assert_eq!(
+        items[0].event_types.as_deref(),
+        Some(&[EventType::ObjectFinalize, EventType::ObjectDelete][..])
+    );
+    assert_eq!(items[0].payload_format, Some(PayloadFormat::None));
+
+    let empty = http::Response::new(r#"{"kind": "storage#notifications"}"#.as_bytes());
+    assert!(
+        ListNotificationsResponse::try_from(empty)
+            .unwrap()
+            .items
+            .is_empty()
+    );
+}
+
+#[test]
+fn parses_finalize_message() {
+    let attributes = [
+        (
+            "notificationConfig",
+            "projects/_/buckets/bucket/notificationConfigs/7",
+        ),
+        ("eventType", "OBJECT_FINALIZE"),
+        ("payloadFormat", "JSON_API_V1"),
+        ("bucketId", "bucket"),
+        ("objectId", "builds/app.tar"),
+        ("objectGeneration", "1591708511706797"),
+        ("overwroteGeneration", "1591708500000000"),
+        ("eventTime", "2020-06-09T13:15:11.706Z"),
+        ("team", "build"),
+    ];
+
+    let data = r#"{
+        "kind": "storage#object",
+        "id": "bucket/builds/app.tar/1591708511706797",
+        "name": "builds/app.tar",
+        "bucket": "bucket",
+        "generation": "1591708511706797",
+        "metageneration": "1",
+        "contentType": "application/x-tar",
+        "size": "1024",
+        "crc32c": "yZRlqg=="
+    }"#;
+
+    let event = StorageEvent::from_message(attributes, data.as_bytes()).unwrap();
+
+    let StorageEvent::ObjectFinalize(event) = event else {
+        panic!("expected a finalize event");
+    };
+
+    let attrs = &event.attributes;
+    assert_eq!(attrs.object_id, "builds/app.tar");
+    assert_eq!(attrs.object_generation, 1591708511706797);
+    assert_eq!(attrs.overwrote_generation, Some(1591708500000000));
+    assert_eq!(attrs.overwritten_by_generation, None);
+    assert!(attrs.event_time.is_some());
+    assert_eq!(
+        attrs.custom_attributes.get("team").map(String::as_str),
+        Some("build")
+    );
+
+    let md = event.metadata.as_ref().unwrap();
+    assert_eq!(md.size, Some(1024));
+    assert_eq!(md.generation, Some(1591708511706797));
+}
+
+#[test]
+fn parses_message_without_payload() {
+    let attributes = [
+        (
+            "notificationConfig",
+            "projects/_/buckets/bucket/notificationConfigs/7",
+        ),
+        ("eventType", "OBJECT_ARCHIVE"),
+        ("payloadFormat", "NONE"),
+        ("bucketId", "bucket"),
+        ("objectId", "object"),
+        ("objectGeneration", "1"),
+        ("overwrittenByGeneration", "2"),
+    ];
+
+    let event = StorageEvent::from_message(attributes, &[]).unwrap();
+
+    assert!(matches!(event, StorageEvent::ObjectArchive(_)));
+    assert_eq!(event.event().attributes.overwritten_by_generation, Some(2));
+    assert!(event.event().metadata.is_none());
+
+    // Missing required attributes are rejected
+    assert!(StorageEvent::from_message([("eventType", "OBJECT_DELETE")], &[]).is_err());
+}
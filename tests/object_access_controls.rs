@@ -0,0 +1,129 @@
+use tame_gcs::{
+    ObjectId,
+    common::Role,
+    object_access_controls::{self, ObjectAccessControl, ObjectAccessControlOptional, ObjectAcl},
+};
+
+mod util;
+
+#[test]
+fn lists() {
+    let list_req = ObjectAcl::default()
+        .list(
+            &ObjectId::new("bucket", "object/with/path").unwrap(),
+            Some(ObjectAccessControlOptional {
+                generation: Some(12),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/object%2Fwith%2Fpath/acl?prettyPrint=false&generation=12")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&list_req, &expected);
+}
+
+#[test]
+fn patches() {
+    let acl = ObjectAccessControl::new("user-someone@example.com", Role::Owner);
+
+    let patch_req = ObjectAcl::default()
+        .patch(
+            &ObjectId::new("bucket", "object").unwrap(),
+            "user-someone@example.com",
+            &acl,
+            None,
+        )
+        .unwrap();
+
+    let req_body = r#"{"entity":"user-someone@example.com","role":"OWNER"}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::PATCH)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/object/acl/user-someone@example.com?prettyPrint=false")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(patch_req, expected);
+}
+
+#[test]
+fn rejects_writer_role() {
+    let acl = ObjectAccessControl::new("group-uploaders@example.com", Role::Writer);
+    let id = ObjectId::new("bucket", "object").unwrap();
+
+    assert_eq!(
+        ObjectAcl::default().insert(&id, &acl, None).unwrap_err(),
+        tame_gcs::Error::InvalidObjectRole
+    );
+    assert_eq!(
+        ObjectAcl::default()
+            .update(&id, "group-uploaders@example.com", &acl, None)
+            .unwrap_err(),
+        tame_gcs::Error::InvalidObjectRole
+    );
+    assert_eq!(
+        tame_gcs::default_object_access_controls::DefaultObjectAcl::default()
+            .patch(
+                &tame_gcs::BucketName::non_validated("bucket"),
+                "group-uploaders@example.com",
+                &acl,
+                None
+            )
+            .unwrap_err(),
+        tame_gcs::Error::InvalidObjectRole
+    );
+}
+
+#[test]
+fn parses_list_response() {
+    let body = r#"{
+        "kind": "storage#objectAccessControls",
+        "items": [
+          {
+            "kind": "storage#objectAccessControl",
+            "id": "bucket/object/1591708511706797/project-owners-123456",
+            "selfLink": "https://www.googleapis.com/storage/v1/b/bucket/o/object/acl/project-owners-123456",
+            "bucket": "bucket",
+            "object": "object",
+            "generation": "1591708511706797",
+            "entity": "project-owners-123456",
+            "role": "OWNER",
+            "projectTeam": {
+              "projectNumber": "123456",
+              "team": "owners"
+            },
+            "etag": "CK29tKPo9OkCEAE="
+          },
+          {
+            "kind": "storage#objectAccessControl",
+            "entity": "allUsers",
+            "role": "READER",
+            "etag": "CK29tKPo9OkCEAE="
+          }
+        ]
+      }"#;
+
+    let response = http::Response::new(body);
+    let list_response =
+        object_access_controls::ListObjectAccessControlsResponse::try_from(response)
+            .expect("parsed list response");
+
+    assert_eq!(list_response.items.len(), 2);
+    assert_eq!(list_response.items[0].generation, Some(1591708511706797));
+    assert_eq!(list_response.items[0].role, Some(Role::Owner));
+    assert_eq!(
+        list_response.items[0]
+            .project_team
+            .as_ref()
+            .and_then(|pt| pt.team.as_deref()),
+        Some("owners")
+    );
+    assert_eq!(list_response.items[1].entity.as_deref(), Some("allUsers"));
+}
@@ -1,7 +1,7 @@
 use http::uri::Authority;
 use tame_gcs::{
     BucketName, ObjectId, ObjectName,
-    common::{Conditionals, StandardQueryParameters},
+    common::{Conditionals, PredefinedAcl, StandardQueryParameters},
     objects::{self, DeleteObjectOptional, InsertObjectOptional, Metadata, Object},
 };
 
@@ -530,6 +530,41 @@ fn patches() {
     util::requests_read_eq(patch_req, expected);
 }
 
+#[test]
+fn updates() {
+    let md = objects::Metadata {
+        content_type: Some("text/plain".to_owned()),
+        ..Default::default()
+    };
+
+    let update_req = Object::default()
+        .update(
+            &ObjectId::new("bucket", "object").unwrap(),
+            &md,
+            Some(objects::UpdateObjectOptional {
+                generation: Some(3),
+                conditionals: Conditionals {
+                    if_metageneration_match: Some(1),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let req_body = r#"{"contentType":"text/plain"}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::PUT)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/object?prettyPrint=false&generation=3&ifMetagenerationMatch=1")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(update_req, expected);
+}
+
 #[test]
 fn parses_patch_response() {
     let body = r#"{
@@ -720,3 +755,648 @@ fn deserializes_complete_rewrite_response() {
         "script.sh"
     );
 }
+
+#[test]
+fn composes() {
+    let md = objects::Metadata {
+        content_type: Some("application/zstd".to_owned()),
+        ..Default::default()
+    };
+
+    let sources = [
+        objects::ComposeSource::new("parts/0"),
+        objects::ComposeSource {
+            name: "parts/1".to_owned(),
+            generation: Some(2),
+            object_preconditions: Some(objects::ComposePreconditions {
+                if_generation_match: Some(2),
+            }),
+        },
+    ];
+
+    let compose_req = Object::default()
+        .compose(
+            &ObjectId::new("bucket", "object/composed").unwrap(),
+            &sources,
+            Some(&md),
+            Some(objects::ComposeObjectOptional {
+                if_generation_match: Some(0),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let req_body = r#"{"sourceObjects":[{"name":"parts/0"},{"name":"parts/1","generation":2,"objectPreconditions":{"ifGenerationMatch":2}}],"destination":{"contentType":"application/zstd"}}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/object%2Fcomposed/compose?prettyPrint=false&ifGenerationMatch=0")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(compose_req, expected);
+}
+
+#[test]
+fn compose_rejects_too_many_sources() {
+    let sources: Vec<_> = (0..33)
+        .map(|i| objects::ComposeSource::new(format!("parts/{i}")))
+        .collect();
+
+    let err = Object::default()
+        .compose(
+            &ObjectId::new("bucket", "object").unwrap(),
+            &sources,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        tame_gcs::Error::InvalidSourceCount {
+            len: 33,
+            min: 1,
+            max: 32
+        }
+    );
+}
+
+#[test]
+fn copies() {
+    let copy_req = Object::default()
+        .copy(
+            &ObjectId::new("source", "object/source.sh").unwrap(),
+            &ObjectId::new("target", "object/target.sh").unwrap(),
+            None,
+            Some(objects::CopyObjectOptional {
+                destination_predefined_acl: Some(PredefinedAcl::Private),
+                destination_conditionals: Some(Conditionals {
+                    if_generation_match: Some(0),
+                    ..Default::default()
+                }),
+                if_source_generation_match: Some(7),
+                source_generation: Some(7),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/storage/v1/b/source/o/object%2Fsource.sh/copyTo/b/target/o/object%2Ftarget.sh?prettyPrint=false&destinationPredefinedAcl=private&ifGenerationMatch=0&ifSourceGenerationMatch=7&sourceGeneration=7")
+        .body(std::io::Cursor::new(Vec::new()))
+        .unwrap();
+
+    util::requests_read_eq(copy_req, expected);
+}
+
+#[test]
+fn downloads_ranges() {
+    let id = ObjectId::new("bucket", "packs/assets.pak").unwrap();
+
+    for (range, header) in [
+        (
+            objects::ByteRange::Bounded {
+                start: 100,
+                end: 199,
+            },
+            "bytes=100-199",
+        ),
+        (objects::ByteRange::From(1024), "bytes=1024-"),
+        (objects::ByteRange::Suffix(512), "bytes=-512"),
+    ] {
+        let download_req = Object::default()
+            .download(
+                &id,
+                Some(objects::DownloadObjectOptional {
+                    range: Some(range),
+                    ..Default::default()
+                }),
+            )
+            .unwrap();
+
+        let expected = http::Request::builder()
+            .method(http::Method::GET)
+            .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/packs%2Fassets.pak?alt=media&prettyPrint=false")
+            .header(http::header::RANGE, header)
+            .body(std::io::empty())
+            .unwrap();
+
+        util::requests_eq(&download_req, &expected);
+    }
+
+    let err = Object::default()
+        .download(
+            &id,
+            Some(objects::DownloadObjectOptional {
+                range: Some(objects::ByteRange::Bounded { start: 10, end: 9 }),
+                ..Default::default()
+            }),
+        )
+        .unwrap_err();
+
+    assert_eq!(err, tame_gcs::Error::InvalidRange);
+}
+
+#[test]
+fn parses_partial_download_response() {
+    let response = http::Response::builder()
+        .status(http::StatusCode::PARTIAL_CONTENT)
+        .header(http::header::CONTENT_RANGE, "bytes 100-103/4096")
+        .body(bytes::Bytes::from_static(b"pack"))
+        .unwrap();
+
+    let download = objects::DownloadObjectResponse::try_from(response).unwrap();
+    let range = download.content_range().unwrap();
+    assert_eq!(
+        range,
+        objects::ContentRange {
+            start: 100,
+            end: 103,
+            total: Some(4096)
+        }
+    );
+    assert_eq!(range.size(), download.len() as u64);
+
+    let response = http::Response::builder()
+        .status(http::StatusCode::PARTIAL_CONTENT)
+        .header(http::header::CONTENT_RANGE, "bytes 0-3/*")
+        .body(bytes::Bytes::from_static(b"pack"))
+        .unwrap();
+    let download = objects::DownloadObjectResponse::try_from(response).unwrap();
+    assert_eq!(download.content_range().unwrap().total, None);
+
+    let response = http::Response::builder()
+        .status(http::StatusCode::PARTIAL_CONTENT)
+        .body(bytes::Bytes::from_static(b"pack"))
+        .unwrap();
+    assert!(objects::DownloadObjectResponse::try_from(response).is_err());
+
+    let response = http::Response::new(bytes::Bytes::from_static(b"pack"));
+    let download = objects::DownloadObjectResponse::try_from(response).unwrap();
+    assert!(download.content_range().is_none());
+}
+
+#[test]
+fn parses_download_headers() {
+    let response = http::Response::builder()
+        .header("x-goog-generation", "1563464155846959")
+        .header("x-goog-metageneration", "2")
+        .header("x-goog-stored-content-encoding", "gzip")
+        .header("x-goog-stored-content-length", "30")
+        .header("x-goog-hash", "crc32c=f+2iuw==")
+        .header("x-goog-hash", "md5=gVBKyp57x/mn4QvE+0fLvg==")
+        .header(http::header::ETAG, "CK+yg+3lvuMCEAE=")
+        .header(http::header::CONTENT_TYPE, "application/octet-stream")
+        .body(bytes::Bytes::from_static(b"content"))
+        .unwrap();
+
+    let download = objects::DownloadObjectResponse::try_from(response).unwrap();
+    assert_eq!(
+        download.headers(),
+        &objects::DownloadHeaders {
+            generation: Some(1563464155846959),
+            metageneration: Some(2),
+            stored_content_encoding: Some("gzip".to_owned()),
+            stored_content_length: Some(30),
+            crc32c: Some("f+2iuw==".to_owned()),
+            md5_hash: Some("gVBKyp57x/mn4QvE+0fLvg==".to_owned()),
+            etag: Some("CK+yg+3lvuMCEAE=".to_owned()),
+            content_type: Some("application/octet-stream".to_owned()),
+        }
+    );
+
+    let response = http::Response::builder()
+        .header(
+            "x-goog-hash",
+            "crc32c=n03x6A==,md5=Ojk9c3dhfxgoKVVHYwFbHQ==",
+        )
+        .body(bytes::Bytes::new())
+        .unwrap();
+    let headers = objects::DownloadObjectResponse::try_from(response)
+        .unwrap()
+        .headers()
+        .clone();
+    assert_eq!(headers.crc32c.as_deref(), Some("n03x6A=="));
+    assert_eq!(
+        headers.md5_hash.as_deref(),
+        Some("Ojk9c3dhfxgoKVVHYwFbHQ==")
+    );
+    assert_eq!(headers.generation, None);
+}
+
+#[test]
+fn insert_with_hashes() {
+    let hashes = tame_gcs::hashing::ObjectHashes::calculate(b"great content", None);
+
+    let insert_req = Object::default()
+        .insert_simple(
+            &ObjectId::new("bucket", "object").unwrap(),
+            "great content",
+            13,
+            Some(InsertObjectOptional {
+                hashes: Some(&hashes),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/upload/storage/v1/b/bucket/o?name=object&uploadType=media&prettyPrint=false")
+        .header(http::header::CONTENT_TYPE, "application/octet-stream")
+        .header(http::header::CONTENT_LENGTH, 13)
+        .header("x-goog-hash", format!("crc32c={}", hashes.crc32c.as_deref().unwrap()))
+        .body("great content")
+        .unwrap();
+
+    util::requests_eq(&insert_req, &expected);
+
+    let metadata = Metadata {
+        name: Some("object".to_owned()),
+        content_type: Some("text/plain".to_owned()),
+        ..Default::default()
+    };
+
+    let insert_req = Object::default()
+        .insert_multipart(
+            &BucketName::non_validated("bucket"),
+            std::io::Cursor::new("great content"),
+            13,
+            &metadata,
+            Some(InsertObjectOptional {
+                hashes: Some(&hashes),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let mut body = String::new();
+    std::io::Read::read_to_string(&mut insert_req.into_body(), &mut body).unwrap();
+    assert!(body.contains(&format!(
+        r#""crc32c":"{}""#,
+        hashes.crc32c.as_deref().unwrap()
+    )));
+}
+
+#[test]
+fn verifies_download_hashes() {
+    let response = http::Response::builder()
+        .header("x-goog-hash", "crc32c=4waSgw==")
+        .body(bytes::Bytes::from_static(b"123456789"))
+        .unwrap();
+
+    let download = objects::DownloadObjectResponse::try_from(response).unwrap();
+    let hashes = tame_gcs::hashing::ObjectHashes::from(download.headers());
+    assert!(hashes.verify(&download, None).is_ok());
+    assert!(hashes.verify(b"corrupted", None).is_err());
+}
+
+#[test]
+fn ignores_malformed_download_headers() {
+    let response = http::Response::builder()
+        .header("x-goog-generation", "not-a-number")
+        .header("x-goog-metageneration", "2")
+        .header(
+            http::header::ETAG,
+            http::HeaderValue::from_bytes(b"\xff\xfe").unwrap(),
+        )
+        .header("x-goog-hash", "crc32c=4waSgw==")
+        .body(bytes::Bytes::from_static(b"123456789"))
+        .unwrap();
+
+    let download = objects::DownloadObjectResponse::try_from(response).unwrap();
+    let headers = download.headers();
+    assert_eq!(headers.generation, None);
+    assert_eq!(headers.metageneration, Some(2));
+    assert_eq!(headers.etag, None);
+    assert_eq!(headers.crc32c.as_deref(), Some("4waSgw=="));
+}
+
+#[test]
+fn streams_verified_download() {
+    use std::io::Read;
+
+    let content = TEST_CONTENT.as_bytes();
+    let hashes = tame_gcs::hashing::ObjectHashes::calculate(content, None);
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert("x-goog-hash", hashes.to_header_value().unwrap().unwrap());
+    let headers = objects::DownloadHeaders::from(&headers);
+
+    let mut reader = headers.verifying_reader(std::io::Cursor::new(content));
+    let mut buf = [0u8; 100];
+    let mut read = Vec::new();
+    loop {
+        let len = reader.read(&mut buf).unwrap();
+        if len == 0 {
+            break;
+        }
+        read.extend_from_slice(&buf[..len]);
+    }
+    assert_eq!(read, content);
+
+    let mut corrupted = content.to_vec();
+    corrupted[7] ^= 0xff;
+
+    let mut reader = headers.verifying_reader(std::io::Cursor::new(corrupted));
+    let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(matches!(
+        err.get_ref()
+            .and_then(|err| err.downcast_ref::<tame_gcs::Error>()),
+        Some(tame_gcs::Error::HashMismatch { .. })
+    ));
+}
+
+#[cfg(feature = "async-download")]
+#[test]
+fn streams_verified_download_async() {
+    use futures_test::io::AsyncReadTestExt;
+    use futures_util::io::{AsyncReadExt, Cursor};
+
+    let content = TEST_CONTENT.as_bytes();
+    let hashes = tame_gcs::hashing::ObjectHashes::calculate(content, None);
+
+    let reader = tame_gcs::hashing::VerifyingReader::new(
+        Cursor::new(content).interleave_pending().limited(37),
+        tame_gcs::hashing::HashVerifier::new(hashes.clone()),
+    );
+    futures::pin_mut!(reader);
+
+    let mut read = Vec::new();
+    futures::executor::block_on(reader.read_to_end(&mut read)).unwrap();
+    assert_eq!(read, content);
+
+    let reader = tame_gcs::hashing::VerifyingReader::new(
+        Cursor::new(&content[1..]),
+        tame_gcs::hashing::HashVerifier::new(hashes),
+    );
+    futures::pin_mut!(reader);
+
+    let err = futures::executor::block_on(reader.read_to_end(&mut Vec::new())).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn deserializes_full_metadata() {
+    let body = r#"{
+        "kind": "storage#object",
+        "id": "cache/builds/linux.tar.zst/1563464155846959",
+        "name": "builds/linux.tar.zst",
+        "bucket": "cache",
+        "generation": "1563464155846959",
+        "metageneration": "2",
+        "cacheControl": "no-cache",
+        "componentCount": 32,
+        "temporaryHold": true,
+        "eventBasedHold": false,
+        "retentionExpirationTime": "2019-08-18T15:35:55.846Z",
+        "retention": {
+            "mode": "Locked",
+            "retainUntilTime": "2030-01-01T00:00:00Z"
+        },
+        "customTime": "2019-07-18T00:00:00Z",
+        "timeDeleted": "2019-07-20T15:35:55.846Z",
+        "softDeleteTime": "2019-07-20T15:35:55.846Z",
+        "hardDeleteTime": "2019-07-27T15:35:55.846Z",
+        "timeFinalized": "2019-07-18T15:35:55.846Z",
+        "kmsKeyName": "projects/p/locations/l/keyRings/r/cryptoKeys/k/cryptoKeyVersions/1",
+        "owner": {
+            "entity": "user-builds@example.com",
+            "entityId": "00b4903a97"
+        },
+        "acl": [{
+            "kind": "storage#objectAccessControl",
+            "entity": "allUsers",
+            "role": "READER"
+        }],
+        "contexts": {
+            "custom": {
+                "branch": {
+                    "value": "main",
+                    "createTime": "2019-07-18T15:35:55.846Z",
+                    "updateTime": "2019-07-18T15:35:55.846Z"
+                }
+            }
+        }
+    }"#;
+
+    let md: Metadata = serde_json::from_str(body).unwrap();
+
+    assert_eq!(md.cache_control.as_deref(), Some("no-cache"));
+    assert_eq!(md.component_count, Some(32));
+    assert_eq!(md.temporary_hold, Some(true));
+    assert_eq!(md.event_based_hold, Some(false));
+    assert!(md.retention_expiration_time.is_some());
+    assert!(md.custom_time.is_some());
+    assert!(md.time_deleted.is_some());
+    assert!(md.soft_delete_time.is_some());
+    assert!(md.hard_delete_time.is_some());
+    assert!(md.time_finalized.is_some());
+    assert!(md.kms_key_name.is_some());
+    assert_eq!(
+        md.owner.unwrap().entity.as_deref(),
+        Some("user-builds@example.com")
+    );
+    assert_eq!(md.acl.unwrap().len(), 1);
+
+    let retention = md.retention.unwrap();
+    assert_eq!(retention.mode, Some(objects::RetentionMode::Locked));
+    assert_eq!(
+        retention.retain_until_time,
+        Some(time::macros::datetime!(2030-01-01 0:00 UTC))
+    );
+
+    let contexts = md.contexts.unwrap().custom.unwrap();
+    assert_eq!(contexts["branch"].value.as_deref(), Some("main"));
+    assert!(contexts["branch"].update_time.is_some());
+}
+
+#[test]
+fn serializes_writable_metadata() {
+    let md = Metadata {
+        cache_control: Some("public, max-age=60".to_owned()),
+        custom_time: Some(time::macros::datetime!(2019-07-18 0:00 UTC)),
+        temporary_hold: Some(false),
+        event_based_hold: Some(true),
+        retention: Some(objects::ObjectRetention {
+            mode: Some(objects::RetentionMode::Unlocked),
+            retain_until_time: Some(time::macros::datetime!(2030-01-01 0:00 UTC)),
+        }),
+        // Read-only fields are never sent
+        component_count: Some(2),
+        time_finalized: Some(time::macros::datetime!(2019-07-18 0:00 UTC)),
+        ..Default::default()
+    };
+
+    util::cmp_strings(
+        r#"{"customTime":"2019-07-18T00:00:00Z","cacheControl":"public, max-age=60","temporaryHold":false,"eventBasedHold":true,"retention":{"mode":"Unlocked","retainUntilTime":"2030-01-01T00:00:00Z"}}"#,
+        &serde_json::to_string(&md).unwrap(),
+    );
+}
+
+#[test]
+fn patches_with_cleared_fields() {
+    let mut patch = objects::MetadataPatch {
+        content_disposition: objects::Patch::Clear,
+        custom_time: objects::Patch::Clear,
+        cache_control: "no-store".to_owned().into(),
+        temporary_hold: objects::Patch::Set(true),
+        ..Default::default()
+    };
+    patch.set_metadata("yanked", "true");
+    patch.clear_metadata("channel");
+
+    let patch_req = Object::default()
+        .patch(&ObjectId::new("bucket", "object").unwrap(), &patch, None)
+        .unwrap();
+
+    let req_body = r#"{"contentDisposition":null,"cacheControl":"no-store","customTime":null,"temporaryHold":true,"metadata":{"channel":null,"yanked":"true"}}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::PATCH)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/object?prettyPrint=false")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(patch_req, expected);
+
+    // Unchanged keys are left out, and all keys can be removed at once
+    let mut entries = std::collections::BTreeMap::new();
+    entries.insert("kept".to_owned(), objects::Patch::Unchanged);
+    entries.insert("updated".to_owned(), objects::Patch::Set("yes".to_owned()));
+
+    let patch = objects::MetadataPatch {
+        metadata: objects::Patch::Set(entries),
+        custom_time: objects::Patch::Set(time::macros::datetime!(2030-01-01 0:00 UTC)),
+        ..Default::default()
+    };
+    util::cmp_strings(
+        r#"{"customTime":"2030-01-01T00:00:00Z","metadata":{"updated":"yes"}}"#,
+        &serde_json::to_string(&patch).unwrap(),
+    );
+
+    let patch = objects::MetadataPatch {
+        metadata: objects::Patch::Clear,
+        ..Default::default()
+    };
+    util::cmp_strings(
+        r#"{"metadata":null}"#,
+        &serde_json::to_string(&patch).unwrap(),
+    );
+}
+
+#[test]
+fn lists_soft_deleted() {
+    let list_req = Object::default()
+        .list(
+            &BucketName::non_validated("cache"),
+            Some(objects::ListOptional {
+                prefix: Some("builds/"),
+                soft_deleted: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    assert_eq!(
+        list_req.uri(),
+        "https://storage.googleapis.com/storage/v1/b/cache/o?prettyPrint=false&prefix=builds%2F&softDeleted=true"
+    );
+
+    let get_req = Object::default()
+        .get(
+            &ObjectId::new("cache", "builds/linux.tar.zst").unwrap(),
+            Some(objects::GetObjectOptional {
+                generation: Some(1563464155846959),
+                soft_deleted: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    assert_eq!(
+        get_req.uri(),
+        "https://storage.googleapis.com/storage/v1/b/cache/o/builds%2Flinux.tar.zst?alt=json&prettyPrint=false&generation=1563464155846959&softDeleted=true"
+    );
+}
+
+#[test]
+fn restores() {
+    let restore_req = Object::default()
+        .restore(
+            &ObjectId::new("cache", "builds/linux.tar.zst").unwrap(),
+            1563464155846959,
+            Some(objects::RestoreObjectOptional {
+                copy_source_acl: true,
+                conditionals: Conditionals {
+                    if_generation_match: Some(0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/storage/v1/b/cache/o/builds%2Flinux.tar.zst/restore?generation=1563464155846959&prettyPrint=false&copySourceAcl=true&ifGenerationMatch=0")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&restore_req, &expected);
+}
+
+#[test]
+fn bulk_restores() {
+    let bulk_req = Object::default()
+        .bulk_restore(
+            &BucketName::non_validated("cache"),
+            &objects::BulkRestoreOptions {
+                match_globs: vec!["builds/**"],
+                soft_deleted_after_time: Some(time::macros::datetime!(2019-07-18 0:00 UTC)),
+                ..Default::default()
+            },
+            Some(objects::BulkRestoreObjectOptional {
+                user_project: Some("billing"),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let req_body = r#"{"allowOverwrite":false,"matchGlobs":["builds/**"],"softDeletedAfterTime":"2019-07-18T00:00:00Z"}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/storage/v1/b/cache/o/bulkRestore?prettyPrint=false&userProject=billing")
+        .header("content-type", "application/json")
+        .header("content-length", req_body.len())
+        .body(std::io::Cursor::new(req_body))
+        .unwrap();
+
+    util::requests_read_eq(bulk_req, expected);
+
+    let body = r#"{
+        "kind": "storage#operation",
+        "name": "projects/_/buckets/cache/operations/c2VhcmNo",
+        "selfLink": "https://www.googleapis.com/storage/v1/b/cache/operations/c2VhcmNo",
+        "done": false,
+        "metadata": {
+            "@type": "type.googleapis.com/google.storage.control.v2.BulkRestoreObjectsMetadata"
+        }
+    }"#;
+
+    let response = objects::BulkRestoreResponse::try_from(http::Response::new(body)).unwrap();
+    assert_eq!(
+        response.operation.name,
+        "projects/_/buckets/cache/operations/c2VhcmNo"
+    );
+    assert!(!response.operation.done);
+    assert!(response.operation.error.is_none());
+}
@@ -0,0 +1,255 @@
+use tame_gcs::{
+    ObjectId,
+    hashing::ObjectHashes,
+    objects::{self, Metadata, Object},
+};
+
+mod util;
+
+#[test]
+fn plans_parts() {
+    let upload = Object::default()
+        .parallel_composite_upload(
+            &ObjectId::new("bucket", "artifact.zip").unwrap(),
+            10,
+            4,
+            "tmp/upload-1/",
+        )
+        .unwrap();
+
+    let parts: Vec<_> = upload
+        .parts()
+        .iter()
+        .map(|part| (part.name.as_str(), part.offset, part.length))
+        .collect();
+    assert_eq!(
+        parts,
+        [
+            ("tmp/upload-1/0", 0, 3),
+            ("tmp/upload-1/1", 3, 3),
+            ("tmp/upload-1/2", 6, 3),
+            ("tmp/upload-1/3", 9, 1),
+        ]
+    );
+
+    let insert_req = upload.insert_part(1, "def", None).unwrap();
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/upload/storage/v1/b/bucket/o?name=tmp/upload-1/1&uploadType=media&prettyPrint=false")
+        .header(http::header::CONTENT_TYPE, "application/octet-stream")
+        .header(http::header::CONTENT_LENGTH, 3)
+        .body("def")
+        .unwrap();
+    util::requests_eq(&insert_req, &expected);
+
+    // Sources smaller than the number of parts don't create empty parts
+    let upload = Object::default()
+        .parallel_composite_upload(&ObjectId::new("bucket", "small").unwrap(), 2, 8, "tmp/")
+        .unwrap();
+    assert_eq!(upload.parts().len(), 2);
+
+    assert!(matches!(
+        Object::default().parallel_composite_upload(
+            &ObjectId::new("bucket", "none").unwrap(),
+            2,
+            0,
+            "tmp/"
+        ),
+        Err(tame_gcs::Error::InvalidPartCount)
+    ));
+}
+
+#[test]
+fn resumable_part_init() {
+    let upload = Object::default()
+        .parallel_composite_upload(
+            &ObjectId::new("bucket", "artifact.zip").unwrap(),
+            10,
+            2,
+            "tmp/",
+        )
+        .unwrap();
+
+    let init_req = upload
+        .resumable_part_init(
+            1,
+            Some(objects::InsertObjectOptional {
+                user_project: Some("billing"),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let body = r#"{"name":"tmp/1"}"#;
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/upload/storage/v1/b/bucket/o?uploadType=resumable&prettyPrint=false&userProject=billing")
+        .header(http::header::CONTENT_TYPE, "application/json; charset=UTF-8")
+        .header(http::header::CONTENT_LENGTH, body.len())
+        .header("x-upload-content-type", "application/octet-stream")
+        .header("x-upload-content-length", 5)
+        .body(std::io::Cursor::new(body))
+        .unwrap();
+
+    util::requests_read_eq(init_req, expected);
+}
+
+#[test]
+fn composes_in_rounds() {
+    let upload = Object::default()
+        .parallel_composite_upload(
+            &ObjectId::new("bucket", "artifact.zip").unwrap(),
+            70,
+            70,
+            "tmp/",
+        )
+        .unwrap();
+
+    let rounds: Vec<_> = upload
+        .rounds()
+        .iter()
+        .map(|round| {
+            round
+                .iter()
+                .map(|step| (step.destination.as_str(), step.sources.len()))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert_eq!(
+        rounds,
+        [
+            vec![
+                ("tmp/compose-0-0", 32),
+                ("tmp/compose-0-1", 32),
+                ("tmp/compose-0-2", 6)
+            ],
+            vec![("artifact.zip", 3)],
+        ]
+    );
+
+    let requests = upload.compose_requests(None, None).unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[1][0].uri(),
+        "https://storage.googleapis.com/storage/v1/b/bucket/o/artifact.zip/compose?prettyPrint=false"
+    );
+
+    let cleanup = upload.cleanup_requests(None).unwrap();
+    assert_eq!(cleanup.len(), 73);
+    assert!(
+        cleanup
+            .iter()
+            .all(|req| req.method() == http::Method::DELETE)
+    );
+    assert_eq!(
+        cleanup[72].uri(),
+        "https://storage.googleapis.com/storage/v1/b/bucket/o/tmp%2Fcompose-0-2?prettyPrint=false"
+    );
+}
+
+#[test]
+fn bills_every_request() {
+    let upload = Object::default()
+        .parallel_composite_upload(
+            &ObjectId::new("bucket", "artifact.zip").unwrap(),
+            70,
+            70,
+            "tmp/",
+        )
+        .unwrap();
+
+    let rounds = upload
+        .compose_requests(
+            None,
+            Some(objects::ComposeObjectOptional {
+                user_project: Some("billing"),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+    let cleanup = upload
+        .cleanup_requests(Some(objects::CleanupCompositeOptional {
+            user_project: Some("billing"),
+            ..Default::default()
+        }))
+        .unwrap();
+
+    let uris: Vec<_> = rounds
+        .iter()
+        .flatten()
+        .map(|req| req.uri())
+        .chain(cleanup.iter().map(|req| req.uri()))
+        .collect();
+    assert_eq!(uris.len(), 4 + 73);
+    for uri in uris {
+        assert_eq!(uri.query(), Some("prettyPrint=false&userProject=billing"));
+    }
+}
+
+#[test]
+fn verifies_combined_crc32c() {
+    let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+
+    let mut upload = Object::default()
+        .parallel_composite_upload(
+            &ObjectId::new("bucket", "artifact.zip").unwrap(),
+            data.len() as u64,
+            7,
+            "tmp/",
+        )
+        .unwrap();
+
+    assert!(upload.expected_crc32c().is_none());
+
+    for (i, part) in upload.parts().to_vec().into_iter().enumerate() {
+        let content = &data[part.offset as usize..(part.offset + part.length) as usize];
+        let hashes = ObjectHashes::calculate(content, None);
+
+        upload
+            .on_part_uploaded(
+                i,
+                &Metadata {
+                    size: Some(part.length),
+                    crc32c: hashes.crc32c,
+                    generation: Some(100 + i as i64),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+    }
+
+    assert_eq!(
+        upload
+            .on_part_uploaded(
+                0,
+                &Metadata {
+                    size: Some(1),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err(),
+        tame_gcs::Error::PartSizeMismatch {
+            expected: upload.parts()[0].length,
+            found: 1
+        }
+    );
+
+    // The parts are composed at the generations that were uploaded
+    let mut requests = upload.compose_requests(None, None).unwrap();
+    let mut body = String::new();
+    std::io::Read::read_to_string(requests[0][0].body_mut(), &mut body).unwrap();
+    assert!(body.contains(r#"{"name":"tmp/0","generation":100}"#));
+    assert!(body.contains(r#"{"name":"tmp/6","generation":106}"#));
+
+    let composed = Metadata {
+        crc32c: ObjectHashes::calculate(&data, None).crc32c,
+        ..Default::default()
+    };
+    assert!(upload.verify(&composed).is_ok());
+
+    let corrupted = Metadata {
+        crc32c: ObjectHashes::calculate(&data[1..], None).crc32c,
+        ..Default::default()
+    };
+    assert!(upload.verify(&corrupted).is_err());
+}
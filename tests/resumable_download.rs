@@ -0,0 +1,199 @@
+use tame_gcs::{ObjectId, objects::Object};
+
+mod util;
+
+fn response(status: http::StatusCode, headers: &[(&str, &str)]) -> http::Response<()> {
+    let mut builder = http::Response::builder().status(status);
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    builder.body(()).unwrap()
+}
+
+#[test]
+fn resumes_from_last_byte() {
+    let mut download =
+        Object::default().resumable_download(&ObjectId::new("bucket", "pack.pak").unwrap(), None);
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/pack.pak?alt=media&prettyPrint=false")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&download.next_request().unwrap(), &expected);
+
+    download
+        .on_response(&response(
+            http::StatusCode::OK,
+            &[
+                ("x-goog-generation", "1563464155846959"),
+                ("x-goog-stored-content-length", "4096"),
+                ("content-length", "4096"),
+            ],
+        ))
+        .unwrap();
+    download.on_data(&[0u8; 1000]);
+
+    assert_eq!(download.generation(), Some(1563464155846959));
+    assert_eq!(download.total_size(), Some(4096));
+    assert!(!download.is_complete());
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/pack.pak?alt=media&prettyPrint=false&generation=1563464155846959&ifGenerationMatch=1563464155846959")
+        .header(http::header::RANGE, "bytes=1000-")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&download.next_request().unwrap(), &expected);
+
+    download
+        .on_response(&response(
+            http::StatusCode::PARTIAL_CONTENT,
+            &[
+                ("x-goog-generation", "1563464155846959"),
+                ("content-range", "bytes 1000-4095/4096"),
+            ],
+        ))
+        .unwrap();
+    download.on_data(&[0u8; 3096]);
+
+    assert!(download.is_complete());
+}
+
+#[test]
+fn downloads_explicit_generation() {
+    // The generation may be noncurrent, so must be requested rather than
+    // only used as a precondition on the live object
+    let download = Object::default()
+        .resumable_download(&ObjectId::new("bucket", "pack.pak").unwrap(), Some(42));
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/pack.pak?alt=media&prettyPrint=false&generation=42&ifGenerationMatch=42")
+        .body(std::io::empty())
+        .unwrap();
+
+    util::requests_eq(&download.next_request().unwrap(), &expected);
+}
+
+#[test]
+fn rejects_spliced_responses() {
+    let id = ObjectId::new("bucket", "pack.pak").unwrap();
+    let mut download = Object::default().resumable_download(&id, Some(7));
+    download
+        .on_response(&response(
+            http::StatusCode::OK,
+            &[("x-goog-generation", "7"), ("content-length", "100")],
+        ))
+        .unwrap();
+    download.on_data(&[0u8; 10]);
+
+    // The server ignored the range
+    assert!(
+        download
+            .on_response(&response(
+                http::StatusCode::OK,
+                &[("x-goog-generation", "7")]
+            ))
+            .is_err()
+    );
+
+    // The range doesn't start where we left off
+    assert!(
+        download
+            .on_response(&response(
+                http::StatusCode::PARTIAL_CONTENT,
+                &[
+                    ("x-goog-generation", "7"),
+                    ("content-range", "bytes 0-99/100")
+                ],
+            ))
+            .is_err()
+    );
+
+    // The object was replaced
+    assert_eq!(
+        download
+            .on_response(&response(
+                http::StatusCode::PARTIAL_CONTENT,
+                &[
+                    ("x-goog-generation", "8"),
+                    ("content-range", "bytes 10-99/100")
+                ],
+            ))
+            .unwrap_err(),
+        tame_gcs::Error::GenerationMismatch {
+            expected: 7,
+            found: 8
+        }
+    );
+
+    assert_eq!(
+        download
+            .on_response(&response(http::StatusCode::PRECONDITION_FAILED, &[]))
+            .unwrap_err(),
+        tame_gcs::Error::from(http::StatusCode::PRECONDITION_FAILED)
+    );
+
+    // The download state is unchanged by failed responses
+    assert_eq!(download.received(), 10);
+}
+
+#[test]
+fn detects_transcoding() {
+    let id = ObjectId::new("bucket", "logs.txt").unwrap();
+
+    let mut download = Object::default().resumable_download(&id, None);
+    download
+        .on_response(&response(
+            http::StatusCode::OK,
+            &[
+                ("x-goog-generation", "7"),
+                ("x-goog-stored-content-encoding", "gzip"),
+                ("x-goog-stored-content-length", "30"),
+                ("content-length", "120"),
+            ],
+        ))
+        .unwrap();
+
+    // The total is the length of the decompressed response, not the stored object
+    assert!(download.is_transcoded());
+    assert_eq!(download.total_size(), Some(120));
+    download.on_data(&[0u8; 30]);
+    assert!(!download.is_complete());
+
+    let mut download = Object::default().resumable_download(&id, None);
+    download
+        .on_response(&response(
+            http::StatusCode::OK,
+            &[
+                ("x-goog-generation", "7"),
+                ("x-goog-stored-content-encoding", "gzip"),
+                ("x-goog-stored-content-length", "30"),
+            ],
+        ))
+        .unwrap();
+
+    // The length of a chunked transcoded response isn't known up front
+    assert!(download.is_transcoded());
+    assert_eq!(download.total_size(), None);
+
+    let mut download = Object::default().resumable_download(&id, None);
+    download
+        .on_response(&response(
+            http::StatusCode::OK,
+            &[
+                ("x-goog-generation", "7"),
+                ("x-goog-stored-content-encoding", "gzip"),
+                ("x-goog-stored-content-length", "30"),
+                ("content-encoding", "gzip"),
+                ("content-length", "30"),
+            ],
+        ))
+        .unwrap();
+
+    assert!(!download.is_transcoded());
+    assert_eq!(download.total_size(), Some(30));
+}
@@ -1,6 +1,6 @@
 use tame_gcs::{
     BucketName, ObjectName,
-    objects::{Object, ResumableSession},
+    objects::{self, Object, ResumableSession},
 };
 
 mod util;
@@ -61,3 +61,249 @@ fn resumable_append() {
 
     util::requests_eq(&append_req, &expected);
 }
+
+fn partial(persisted: u64) -> objects::ResumableInsertResponse {
+    objects::ResumableInsertResponse {
+        metadata: objects::ResumableInsertResponseMetadata::PartialSize(persisted),
+    }
+}
+
+#[test]
+fn resumable_upload_chunks() {
+    const CHUNK: u64 = objects::RESUMABLE_CHUNK_ALIGNMENT;
+
+    let session = ResumableSession("https://killedbygoogle.com/".parse().unwrap());
+    let mut upload =
+        objects::ResumableUpload::new(session.clone(), Some(CHUNK * 2 + 10)).with_chunk_size(1);
+
+    assert_eq!(upload.next_range(), 0..CHUNK);
+
+    let append_req = upload.append("first", CHUNK, false).unwrap();
+    let expected = http::Request::builder()
+        .method(http::Method::PUT)
+        .uri(session.clone())
+        .header(http::header::CONTENT_LENGTH, CHUNK)
+        .header(
+            http::header::CONTENT_RANGE,
+            format!("bytes 0-{}/{}", CHUNK - 1, CHUNK * 2 + 10),
+        )
+        .body("first")
+        .unwrap();
+    util::requests_eq(&append_req, &expected);
+
+    // Only part of the second chunk was persisted before the connection dropped
+    upload.on_response(partial(CHUNK)).unwrap();
+    assert!(upload.append("second", CHUNK, false).is_ok());
+
+    let status_req = upload.status().unwrap();
+    let expected = http::Request::builder()
+        .method(http::Method::PUT)
+        .uri(session.clone())
+        .header(http::header::CONTENT_LENGTH, 0)
+        .header(
+            http::header::CONTENT_RANGE,
+            format!("bytes */{}", CHUNK * 2 + 10),
+        )
+        .body(())
+        .unwrap();
+    util::requests_eq(&status_req, &expected);
+
+    upload.on_response(partial(CHUNK + 1000)).unwrap();
+    assert_eq!(upload.next_range(), CHUNK + 1000..CHUNK * 2 + 10);
+
+    // Chunks that aren't the last must be aligned
+    assert_eq!(
+        upload.append("unaligned", 1000, false).unwrap_err(),
+        tame_gcs::Error::UnalignedChunk { len: 1000 }
+    );
+
+    upload.on_response(partial(CHUNK * 2)).unwrap();
+    assert_eq!(upload.next_range(), CHUNK * 2..CHUNK * 2 + 10);
+
+    let append_req = upload.append("last", 10, false).unwrap();
+    let expected = http::Request::builder()
+        .method(http::Method::PUT)
+        .uri(session)
+        .header(http::header::CONTENT_LENGTH, 10)
+        .header(
+            http::header::CONTENT_RANGE,
+            format!("bytes {}-{}/{}", CHUNK * 2, CHUNK * 2 + 9, CHUNK * 2 + 10),
+        )
+        .body("last")
+        .unwrap();
+    util::requests_eq(&append_req, &expected);
+
+    let metadata = upload
+        .on_response(objects::ResumableInsertResponse {
+            metadata: objects::ResumableInsertResponseMetadata::Complete(Default::default()),
+        })
+        .unwrap();
+    assert!(metadata.is_some());
+    assert!(upload.is_complete());
+    assert_eq!(upload.persisted(), CHUNK * 2 + 10);
+}
+
+#[test]
+fn resumable_upload_unknown_size() {
+    const CHUNK: u64 = objects::RESUMABLE_CHUNK_ALIGNMENT;
+
+    let session = ResumableSession("https://killedbygoogle.com/".parse().unwrap());
+    let mut upload = objects::ResumableUpload::new(session.clone(), None)
+        .with_chunk_size(CHUNK)
+        .with_hashes(tame_gcs::hashing::ObjectHashes {
+            crc32c: Some("4waSgw==".to_owned()),
+            md5_hash: None,
+        });
+
+    let append_req = upload.append("first", CHUNK, false).unwrap();
+    assert_eq!(
+        append_req.headers()[http::header::CONTENT_RANGE],
+        format!("bytes 0-{}/*", CHUNK - 1)
+    );
+    assert!(append_req.headers().get("x-goog-hash").is_none());
+
+    let status_req = upload.status().unwrap();
+    assert_eq!(
+        status_req.headers()[http::header::CONTENT_RANGE],
+        "bytes */*"
+    );
+
+    upload.on_response(partial(CHUNK)).unwrap();
+
+    let append_req = upload.append("last", 5, true).unwrap();
+    assert_eq!(
+        append_req.headers()[http::header::CONTENT_RANGE],
+        format!("bytes {}-{}/{}", CHUNK, CHUNK + 4, CHUNK + 5)
+    );
+    assert_eq!(append_req.headers()["x-goog-hash"], "crc32c=4waSgw==");
+
+    // The total is known once the last chunk has been sent
+    assert_eq!(upload.total_size(), Some(CHUNK + 5));
+    let status_req = upload.status().unwrap();
+    assert_eq!(
+        status_req.headers()[http::header::CONTENT_RANGE],
+        format!("bytes */{}", CHUNK + 5)
+    );
+
+    // The source ended exactly on a chunk boundary
+    let mut upload = objects::ResumableUpload::new(session, None).with_chunk_size(CHUNK);
+    upload.append("first", CHUNK, false).unwrap();
+    upload.on_response(partial(CHUNK)).unwrap();
+
+    assert_eq!(
+        upload.append("", 0, false).unwrap_err(),
+        tame_gcs::Error::EmptyChunk
+    );
+
+    let append_req = upload.append("", 0, true).unwrap();
+    assert_eq!(
+        append_req.headers()[http::header::CONTENT_RANGE],
+        format!("bytes */{CHUNK}")
+    );
+    assert_eq!(
+        upload.status().unwrap().headers()[http::header::CONTENT_RANGE],
+        format!("bytes */{CHUNK}")
+    );
+}
+
+#[test]
+fn resumable_init_with_metadata() {
+    let metadata = objects::Metadata {
+        name: Some("object/with/deep/path".to_owned()),
+        content_type: Some("image/png".to_owned()),
+        ..Default::default()
+    };
+
+    let init_req = Object::default()
+        .resumable_insert_init_with_metadata(
+            &BucketName::non_validated("bucket"),
+            &metadata,
+            Some(2_000_000),
+            Some("https://example.com"),
+            Some(objects::InsertObjectOptional {
+                conditionals: tame_gcs::common::Conditionals {
+                    if_generation_match: Some(0),
+                    ..Default::default()
+                },
+                predefined_acl: Some(tame_gcs::common::PredefinedAcl::PublicRead),
+                kms_key_name: Some("projects/p/locations/l/keyRings/r/cryptoKeys/k"),
+                user_project: Some("billing"),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let body = r#"{"name":"object/with/deep/path","contentType":"image/png"}"#;
+
+    let expected = http::Request::builder()
+        .method(http::Method::POST)
+        .uri("https://storage.googleapis.com/upload/storage/v1/b/bucket/o?uploadType=resumable&prettyPrint=false&ifGenerationMatch=0&kmsKeyName=projects%2Fp%2Flocations%2Fl%2FkeyRings%2Fr%2FcryptoKeys%2Fk&predefinedAcl=publicRead&userProject=billing")
+        .header(http::header::CONTENT_TYPE, "application/json; charset=UTF-8")
+        .header(http::header::CONTENT_LENGTH, body.len())
+        .header("x-upload-content-type", "image/png")
+        .header("x-upload-content-length", 2_000_000)
+        .header(http::header::ORIGIN, "https://example.com")
+        .body(std::io::Cursor::new(body))
+        .unwrap();
+
+    util::requests_read_eq(init_req, expected);
+}
+
+#[test]
+fn resumable_init_requires_name() {
+    assert_eq!(
+        Object::default()
+            .resumable_insert_init_with_metadata(
+                &BucketName::non_validated("bucket"),
+                &objects::Metadata::default(),
+                None,
+                None,
+                None,
+            )
+            .unwrap_err(),
+        tame_gcs::Error::MissingMetadata("name")
+    );
+}
+
+#[test]
+fn parses_resumable_responses() {
+    use tame_gcs::ApiResponse;
+
+    let parse = |status: http::StatusCode, range: Option<&str>| {
+        let mut builder = http::Response::builder().status(status);
+        if let Some(range) = range {
+            builder = builder.header(http::header::RANGE, range);
+        }
+        objects::ResumableInsertResponse::try_from_parts(builder.body(&b""[..]).unwrap())
+            .map(|res| res.metadata)
+    };
+
+    assert!(matches!(
+        parse(http::StatusCode::PERMANENT_REDIRECT, Some("bytes=0-262143")),
+        Ok(objects::ResumableInsertResponseMetadata::PartialSize(
+            262144
+        ))
+    ));
+    assert!(matches!(
+        parse(http::StatusCode::PERMANENT_REDIRECT, None),
+        Ok(objects::ResumableInsertResponseMetadata::PartialSize(0))
+    ));
+    assert!(matches!(
+        parse(http::StatusCode::NOT_FOUND, None),
+        Ok(objects::ResumableInsertResponseMetadata::SessionExpired)
+    ));
+    assert!(matches!(
+        parse(http::StatusCode::GONE, None),
+        Ok(objects::ResumableInsertResponseMetadata::SessionExpired)
+    ));
+    assert!(parse(http::StatusCode::SERVICE_UNAVAILABLE, None).is_err());
+
+    let session = ResumableSession("https://killedbygoogle.com/".parse().unwrap());
+    let mut upload = objects::ResumableUpload::new(session, Some(10));
+    assert!(matches!(
+        upload.on_response(objects::ResumableInsertResponse {
+            metadata: objects::ResumableInsertResponseMetadata::SessionExpired,
+        }),
+        Err(tame_gcs::Error::ExpiredSession)
+    ));
+}
@@ -0,0 +1,181 @@
+use tame_gcs::{
+    hashing::ObjectHashes,
+    objects::{Metadata, Object},
+};
+
+mod util;
+
+fn metadata(data: &[u8]) -> Metadata {
+    Metadata {
+        bucket: Some("bucket".to_owned()),
+        name: Some("packs/assets.pak".to_owned()),
+        generation: Some(1563464155846959),
+        size: Some(data.len() as u64),
+        crc32c: ObjectHashes::calculate(data, None).crc32c,
+        ..Default::default()
+    }
+}
+
+fn slice_response(generation: &str, content_range: &str) -> http::Response<()> {
+    http::Response::builder()
+        .status(http::StatusCode::PARTIAL_CONTENT)
+        .header("x-goog-generation", generation)
+        .header(http::header::CONTENT_RANGE, content_range)
+        .body(())
+        .unwrap()
+}
+
+#[test]
+fn plans_slices() {
+    let data = vec![7u8; 10];
+    let mut download = Object::default()
+        .sliced_download(&metadata(&data), 3)
+        .unwrap();
+
+    let slices: Vec<_> = download
+        .slices()
+        .iter()
+        .map(|slice| (slice.offset, slice.length))
+        .collect();
+    assert_eq!(slices, [(0, 4), (4, 4), (8, 2)]);
+
+    let expected = http::Request::builder()
+        .method(http::Method::GET)
+        .uri("https://storage.googleapis.com/storage/v1/b/bucket/o/packs%2Fassets.pak?alt=media&prettyPrint=false&generation=1563464155846959")
+        .header(http::header::RANGE, "bytes=4-7")
+        .body(std::io::empty())
+        .unwrap();
+    util::requests_eq(&download.request(1).unwrap(), &expected);
+
+    assert!(
+        download
+            .on_slice_response(1, &slice_response("1563464155846959", "bytes 4-7/10"))
+            .is_ok()
+    );
+    assert!(
+        download
+            .on_slice_response(1, &slice_response("1563464155846959", "bytes 0-3/10"))
+            .is_err()
+    );
+    assert_eq!(
+        download
+            .on_slice_response(1, &slice_response("1", "bytes 4-7/10"))
+            .unwrap_err(),
+        tame_gcs::Error::GenerationMismatch {
+            expected: 1563464155846959,
+            found: 1
+        }
+    );
+
+    assert_eq!(
+        Object::default()
+            .sliced_download(&Metadata::default(), 3)
+            .err(),
+        Some(tame_gcs::Error::MissingMetadata("bucket"))
+    );
+    assert_eq!(
+        Object::default().sliced_download(&metadata(&data), 0).err(),
+        Some(tame_gcs::Error::InvalidSliceCount)
+    );
+}
+
+#[test]
+fn retries_interrupted_slice() {
+    let data: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+    let mut download = Object::default()
+        .sliced_download(&metadata(&data), 2)
+        .unwrap();
+
+    download
+        .on_slice_response(0, &slice_response("1563464155846959", "bytes 0-499/1000"))
+        .unwrap();
+    download.on_slice_data(0, &data[..500]).unwrap();
+
+    // The connection drops halfway through the second slice
+    download
+        .on_slice_response(1, &slice_response("1563464155846959", "bytes 500-999/1000"))
+        .unwrap();
+    download.on_slice_data(1, &data[500..750]).unwrap();
+    assert!(!download.is_complete());
+
+    // Retrying the slice discards the data from the failed attempt
+    download
+        .on_slice_response(1, &slice_response("1563464155846959", "bytes 500-999/1000"))
+        .unwrap();
+    download.on_slice_data(1, &data[500..]).unwrap();
+
+    assert!(download.is_complete());
+    download.verify().unwrap();
+}
+
+#[test]
+fn empty_object_has_no_slices() {
+    let download = Object::default()
+        .sliced_download(&metadata(&[]), 4)
+        .unwrap();
+
+    assert!(download.slices().is_empty());
+    assert!(download.is_complete());
+    download.verify().unwrap();
+
+    let mut corrupted = metadata(&[]);
+    corrupted.crc32c = ObjectHashes::calculate(b"not empty", None).crc32c;
+    assert!(matches!(
+        Object::default()
+            .sliced_download(&corrupted, 4)
+            .unwrap()
+            .verify(),
+        Err(tame_gcs::Error::HashMismatch { .. })
+    ));
+}
+
+#[test]
+fn verifies_out_of_order_slices() {
+    let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+    let mut download = Object::default()
+        .sliced_download(&metadata(&data), 6)
+        .unwrap();
+
+    let mut buffer = vec![0u8; data.len()];
+    for index in [3, 0, 5, 1, 4, 2] {
+        let slice = download.slices()[index];
+        let content = &data[slice.offset as usize..(slice.offset + slice.length) as usize];
+
+        download
+            .slice_buffer(index, &mut buffer)
+            .copy_from_slice(content);
+
+        assert!(!download.is_complete());
+        for chunk in content.chunks(1000) {
+            download.on_slice_data(index, chunk).unwrap();
+        }
+    }
+
+    assert!(download.is_complete());
+    assert_eq!(buffer, data);
+    download.verify().unwrap();
+
+    // Too much data for a slice
+    let expected = download.slices()[0].length;
+    assert_eq!(
+        download.on_slice_data(0, b"extra").unwrap_err(),
+        tame_gcs::Error::SliceLengthMismatch {
+            expected,
+            found: expected + 5
+        }
+    );
+
+    let mut corrupted = Object::default()
+        .sliced_download(&metadata(&data), 2)
+        .unwrap();
+    assert_eq!(
+        corrupted.verify().unwrap_err(),
+        tame_gcs::Error::InsufficientData
+    );
+    corrupted.on_slice_data(0, &data[..50_000]).unwrap();
+    corrupted.on_slice_data(1, &vec![0u8; 50_000]).unwrap();
+    assert!(matches!(
+        corrupted.verify(),
+        Err(tame_gcs::Error::HashMismatch { .. })
+    ));
+}